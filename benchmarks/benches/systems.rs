@@ -0,0 +1,225 @@
+#[macro_use]
+extern crate criterion;
+
+use std::time::Duration;
+
+use bevy::{asset::AssetPlugin, prelude::*};
+use bevy_tweening::{lens::*, *};
+use criterion::{BenchmarkId, Criterion};
+
+/// Numbers of animated entities of each benchmark.
+const ENTITY_COUNTS: [usize; 2] = [1_000, 10_000];
+
+/// Duration of the animations which must not complete while benchmarking.
+const LONG_DURATION: Duration = Duration::from_secs(3600);
+
+type BatchPosition = BatchTween<Transform, TransformPositionLens>;
+type StaticPosition = Tween<Transform, TransformPositionLens>;
+
+/// Create a headless app with the [`TweeningPlugin`], calling `spawn` to spawn each of the `count`
+/// animated entities.
+///
+/// The app time advances in real time, so the animations which must stay active while
+/// benchmarking either loop, or last for [`LONG_DURATION`].
+fn make_app(count: usize, spawn: impl Fn(&mut World, usize)) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(AssetPlugin)
+        .add_asset::<ColorMaterial>()
+        .add_plugin(TweeningPlugin::default())
+        .register_static_animator::<Transform, StaticPosition>()
+        .register_batch_tween::<Transform, TransformPositionLens>();
+    for index in 0..count {
+        spawn(&mut app.world, index);
+    }
+    app
+}
+
+/// Spawn an entity with a [`Transform`] animated by an [`Animator`] playing `tweenable`.
+fn spawn_animator(world: &mut World, tweenable: impl Tweenable<Transform> + 'static) {
+    world
+        .spawn()
+        .insert(Transform::default())
+        .insert(Animator::new(tweenable));
+}
+
+fn position_lens(index: usize) -> TransformPositionLens {
+    let start = Vec3::new(index as f32, 0., 0.);
+    TransformPositionLens {
+        start,
+        end: start + Vec3::Y,
+    }
+}
+
+fn position_tween(index: usize) -> Tween<Transform> {
+    Tween::new(
+        EaseFunction::QuadraticInOut,
+        TweeningType::PingPong,
+        Duration::from_secs(1),
+        position_lens(index),
+    )
+}
+
+fn scale_tween(duration: Duration) -> Tween<Transform> {
+    Tween::new(
+        EaseFunction::QuadraticInOut,
+        TweeningType::Once,
+        duration,
+        TransformScaleLens {
+            start: Vec3::ONE,
+            end: Vec3::splat(2.),
+        },
+    )
+}
+
+fn rotation_tween(duration: Duration) -> Tween<Transform> {
+    Tween::new(
+        EaseFunction::QuadraticInOut,
+        TweeningType::Once,
+        duration,
+        TransformRotationLens {
+            start: Quat::IDENTITY,
+            end: Quat::from_rotation_z(90_f32.to_radians()),
+        },
+    )
+}
+
+/// Compare the boxed and statically dispatched [`Animator`], and the [`BatchTween`] paths
+/// animating the position of many entities.
+fn animator_vs_batch_tween(c: &mut Criterion) {
+    let mut group = c.benchmark_group("position");
+    for count in ENTITY_COUNTS {
+        group.bench_with_input(BenchmarkId::new("Animator", count), &count, |b, &count| {
+            let mut app = make_app(count, |world, index| {
+                spawn_animator(world, position_tween(index));
+            });
+            b.iter(|| app.update());
+        });
+        group.bench_with_input(
+            BenchmarkId::new("StaticAnimator", count),
+            &count,
+            |b, &count| {
+                let mut app = make_app(count, |world, index| {
+                    let tween: StaticPosition = Tween::new_static(
+                        EaseFunction::QuadraticInOut,
+                        TweeningType::PingPong,
+                        Duration::from_secs(1),
+                        position_lens(index),
+                    );
+                    world
+                        .spawn()
+                        .insert(Transform::default())
+                        .insert(Animator::new_static(tween));
+                });
+                b.iter(|| app.update());
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("BatchTween", count),
+            &count,
+            |b, &count| {
+                let mut app = make_app(count, |world, index| {
+                    world
+                        .spawn()
+                        .insert(Transform::default())
+                        .insert(BatchPosition::new(
+                            EaseFunction::QuadraticInOut,
+                            TweeningType::PingPong,
+                            Duration::from_secs(1),
+                            position_lens(index),
+                        ));
+                });
+                b.iter(|| app.update());
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Tick [`Animator`]s playing composite tweenables. The single [`Tween`] case is benchmarked by
+/// [`animator_vs_batch_tween()`].
+fn animator_tweenables(c: &mut Criterion) {
+    let mut group = c.benchmark_group("animator");
+    for count in ENTITY_COUNTS {
+        group.bench_with_input(BenchmarkId::new("Sequence", count), &count, |b, &count| {
+            let mut app = make_app(count, |world, _index| {
+                spawn_animator(
+                    world,
+                    scale_tween(LONG_DURATION).then(rotation_tween(LONG_DURATION)),
+                );
+            });
+            b.iter(|| app.update());
+        });
+        group.bench_with_input(BenchmarkId::new("Tracks", count), &count, |b, &count| {
+            let mut app = make_app(count, |world, _index| {
+                spawn_animator(
+                    world,
+                    Tracks::new([scale_tween(LONG_DURATION), rotation_tween(LONG_DURATION)]),
+                );
+            });
+            b.iter(|| app.update());
+        });
+    }
+    group.finish();
+}
+
+/// Tick [`Animator`]s completing a loop and sending a [`TweenCompleted`] event each frame.
+fn animator_events(c: &mut Criterion) {
+    let mut group = c.benchmark_group("events");
+    for count in ENTITY_COUNTS {
+        group.bench_with_input(BenchmarkId::new("Tween", count), &count, |b, &count| {
+            let mut app = make_app(count, |world, index| {
+                let tween = Tween::new(
+                    EaseFunction::QuadraticInOut,
+                    TweeningType::Loop,
+                    Duration::from_nanos(1),
+                    position_lens(index),
+                )
+                .with_completed_event(true, index as u64);
+                spawn_animator(world, tween);
+            });
+            b.iter(|| app.update());
+        });
+    }
+    group.finish();
+}
+
+/// Tick [`AssetAnimator`]s each animating its own [`ColorMaterial`].
+fn asset_animator(c: &mut Criterion) {
+    let mut group = c.benchmark_group("asset_animator");
+    for count in ENTITY_COUNTS {
+        group.bench_with_input(
+            BenchmarkId::new("ColorMaterial", count),
+            &count,
+            |b, &count| {
+                let mut app = make_app(count, |world, _index| {
+                    let handle = world
+                        .get_resource_mut::<Assets<ColorMaterial>>()
+                        .unwrap()
+                        .add(ColorMaterial::default());
+                    let tween = Tween::new(
+                        EaseFunction::QuadraticInOut,
+                        TweeningType::PingPong,
+                        Duration::from_secs(1),
+                        ColorMaterialColorLens {
+                            start: Color::RED,
+                            end: Color::BLUE,
+                        },
+                    );
+                    world.spawn().insert(AssetAnimator::new(handle, tween));
+                });
+                b.iter(|| app.update());
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    animator_vs_batch_tween,
+    animator_tweenables,
+    animator_events,
+    asset_animator
+);
+criterion_main!(benches);
@@ -0,0 +1,179 @@
+#![deny(
+    warnings,
+    missing_copy_implementations,
+    trivial_casts,
+    trivial_numeric_casts,
+    unsafe_code,
+    unstable_features,
+    unused_import_braces,
+    unused_qualifications,
+    missing_docs
+)]
+
+//! Derive macros for 🍃 Bevy Tweening.
+//!
+//! This crate is not intended to be used directly. Instead, enable the `derive` feature of
+//! the `bevy_tweening` crate, which re-exports the macros defined here.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident, Lit, Meta, NestedMeta};
+
+/// Derive a lens type for each field of a struct annotated with `#[tween]`.
+///
+/// For each annotated field, a lens type with public `start` and `end` fields of the same type
+/// as the field is generated next to the struct, with the same visibility. The lens implements
+/// `Lens<T>` where `T` is the struct, and interpolates the field with the `Lerp` trait, so the
+/// field type must implement `Lerp<Scalar = f32>`.
+///
+/// The lens type is named after the field in upper camel case, with a `Lens` suffix. For
+/// example a `health_bar_fill` field generates a `HealthBarFillLens` type. The name can be
+/// overridden with `#[tween(lens = "MyLens")]`, for example to disambiguate fields with the
+/// same name in different structs of the same module.
+///
+/// # Example
+///
+/// ```
+/// use bevy_tweening::Lens;
+///
+/// #[derive(Lens)]
+/// struct Player {
+///     #[tween]
+///     health_bar_fill: f32,
+///     #[tween(lens = "PlayerOpacityLens")]
+///     opacity: f32,
+///     name: String,
+/// }
+///
+/// let mut lens = HealthBarFillLens { start: 0., end: 1. };
+/// # let mut player = Player { health_bar_fill: 0., opacity: 1., name: String::new() };
+/// lens.lerp(&mut player, 0.5);
+/// # let _ = PlayerOpacityLens { start: 0., end: 1. };
+/// ```
+#[proc_macro_derive(Lens, attributes(tween))]
+pub fn derive_lens(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_lens(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_lens(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[derive(Lens)] does not support generic types",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "#[derive(Lens)] only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "#[derive(Lens)] only supports structs",
+            ))
+        }
+    };
+
+    let target = &input.ident;
+    let vis = &input.vis;
+    let mut lenses = TokenStream2::new();
+    for field in fields {
+        let attr = match field.attrs.iter().find(|attr| attr.path.is_ident("tween")) {
+            Some(attr) => attr,
+            None => continue,
+        };
+        // Named fields always have an identifier
+        let field_ident = field.ident.as_ref().unwrap();
+        let field_ty = &field.ty;
+        let lens_ident = match parse_lens_name(attr)? {
+            Some(name) => name,
+            None => Ident::new(
+                &format!("{}Lens", to_upper_camel_case(&field_ident.to_string())),
+                Span::call_site(),
+            ),
+        };
+        let doc = format!(
+            "A lens to manipulate the `{}` field of a [`{}`].",
+            field_ident, target
+        );
+        lenses.extend(quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone)]
+            #vis struct #lens_ident {
+                /// Start value of the field.
+                pub start: #field_ty,
+                /// End value of the field.
+                pub end: #field_ty,
+            }
+
+            impl ::bevy_tweening::Lens<#target> for #lens_ident {
+                fn lerp(&mut self, target: &mut #target, ratio: f32) {
+                    target.#field_ident =
+                        ::bevy_tweening::Lerp::lerp(&self.start, &self.end, &ratio);
+                }
+            }
+        });
+    }
+    Ok(lenses)
+}
+
+/// Parse the optional lens name override of a `#[tween]` attribute.
+fn parse_lens_name(attr: &Attribute) -> syn::Result<Option<Ident>> {
+    match attr.parse_meta()? {
+        Meta::Path(_) => Ok(None),
+        Meta::List(list) => {
+            let mut name = None;
+            for nested in &list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("lens") => {
+                        match &nv.lit {
+                            Lit::Str(lit) => name = Some(lit.parse()?),
+                            lit => {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "expected a string literal, like `lens = \"MyLens\"`",
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            nested,
+                            "unknown #[tween] argument, expected `lens = \"...\"`",
+                        ))
+                    }
+                }
+            }
+            Ok(name)
+        }
+        meta => Err(syn::Error::new_spanned(
+            meta,
+            "expected #[tween] or #[tween(lens = \"...\")]",
+        )),
+    }
+}
+
+/// Convert a `snake_case` identifier into `UpperCamelCase`.
+fn to_upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
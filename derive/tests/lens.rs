@@ -0,0 +1,64 @@
+use bevy_tweening::Lens;
+
+#[derive(Lens)]
+struct DummyComponent {
+    #[tween]
+    health_bar_fill: f32,
+    #[tween(lens = "DummyCountLens")]
+    count: u32,
+    #[tween]
+    color: [f32; 4],
+    name: &'static str,
+}
+
+impl Default for DummyComponent {
+    fn default() -> Self {
+        DummyComponent {
+            health_bar_fill: 1.,
+            count: 0,
+            color: [1.; 4],
+            name: "dummy",
+        }
+    }
+}
+
+#[test]
+fn derive_field_lens() {
+    let mut lens = HealthBarFillLens { start: 0., end: 2. };
+    let mut target = DummyComponent::default();
+
+    lens.lerp(&mut target, 0.);
+    assert!(target.health_bar_fill.abs() < 1e-5);
+
+    lens.lerp(&mut target, 1.);
+    assert!((target.health_bar_fill - 2.).abs() < 1e-5);
+
+    lens.lerp(&mut target, 0.3);
+    assert!((target.health_bar_fill - 0.6).abs() < 1e-5);
+    assert_eq!(target.count, 0);
+    assert_eq!(target.name, "dummy");
+}
+
+#[test]
+fn derive_renamed_lens() {
+    let mut lens = DummyCountLens { start: 0, end: 10 };
+    let mut target = DummyComponent::default();
+
+    lens.lerp(&mut target, 0.5);
+    assert_eq!(target.count, 5);
+    assert!((target.health_bar_fill - 1.).abs() < 1e-5);
+}
+
+#[test]
+fn derive_array_lens() {
+    let mut lens = ColorLens {
+        start: [0.; 4],
+        end: [1., 0., 0.5, 1.],
+    };
+    let mut target = DummyComponent::default();
+
+    lens.lerp(&mut target, 0.5);
+    for (actual, expected) in target.color.iter().zip([0.5, 0., 0.25, 0.5]) {
+        assert!((actual - expected).abs() < 1e-5);
+    }
+}
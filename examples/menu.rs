@@ -13,7 +13,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ..Default::default()
         })
         .add_plugins(DefaultPlugins)
-        .add_plugin(TweeningPlugin)
+        .add_plugin(TweeningPlugin::default())
         .add_plugin(WorldInspectorPlugin::new())
         .add_startup_system(setup)
         .run();
@@ -46,20 +46,10 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         .insert(Name::new("menu"))
         .id();
 
-    let mut start_time_ms = 0;
+    let mut names = vec![];
     for text in &["Continue", "New Game", "Settings", "Quit"] {
-        let delay = Delay::new(Duration::from_millis(start_time_ms));
-        start_time_ms += 500;
-        let tween_scale = Tween::new(
-            EaseFunction::BounceOut,
-            TweeningType::Once,
-            Duration::from_secs(2),
-            TransformScaleLens {
-                start: Vec3::splat(0.01),
-                end: Vec3::ONE,
-            },
-        );
-        let seq = delay.then(tween_scale);
+        let name = format!("button:{}", text);
+        names.push(name.clone());
         commands
             .spawn_bundle(NodeBundle {
                 node: Node {
@@ -79,9 +69,8 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 transform: Transform::from_scale(Vec3::splat(0.01)),
                 ..Default::default()
             })
-            .insert(Name::new(format!("button:{}", text)))
+            .insert(Name::new(name))
             .insert(Parent(container))
-            .insert(Animator::new(seq))
             .with_children(|parent| {
                 parent.spawn_bundle(TextBundle {
                     text: Text::with_section(
@@ -100,4 +89,21 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 });
             });
     }
+
+    // Animate all buttons from a single animator on the container, one after the other
+    let animator = Stagger::new(Duration::from_millis(500)).hierarchy_animator(
+        names.iter().map(String::as_str),
+        |_index| {
+            Tween::new(
+                EaseFunction::BounceOut,
+                TweeningType::Once,
+                Duration::from_secs(2),
+                TransformScaleLens {
+                    start: Vec3::splat(0.01),
+                    end: Vec3::ONE,
+                },
+            )
+        },
+    );
+    commands.entity(container).insert(animator);
 }
@@ -12,7 +12,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ..Default::default()
         })
         .add_plugins(DefaultPlugins)
-        .add_plugin(TweeningPlugin)
+        .add_plugin(TweeningPlugin::default())
         .add_startup_system(setup)
         .add_system(update_text)
         .run();
@@ -13,7 +13,7 @@ fn main() {
             ..Default::default()
         })
         .add_plugins(DefaultPlugins)
-        .add_plugin(TweeningPlugin)
+        .add_plugin(TweeningPlugin::default())
         .add_plugin(EguiPlugin)
         .add_startup_system(setup)
         .init_resource::<Options>()
@@ -135,7 +135,7 @@ fn options_panel(mut egui_context: ResMut<EguiContext>, mut options: ResMut<Opti
                 ui.add(
                     egui::DragValue::new(&mut local_options.speed)
                         .speed(0.01)
-                        .clamp_range(0.01..=100.),
+                        .clamp_range(-10.0..=10.),
                 );
             });
         });
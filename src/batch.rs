@@ -0,0 +1,384 @@
+//! Batched animation of large numbers of entities with simple tweens.
+//!
+//! A [`BatchTween`] is a plain-data component animating a single component of its entity with
+//! a single tween segment, like moving, scaling, or fading a sprite. Unlike an [`Animator`], it
+//! holds no trait object and no callback, and all the batch tweens of a given type are ticked in
+//! parallel on the [`ComputeTaskPool`] by the [`batch_tween_system::<T, L>`]. This makes it the
+//! fastest way to animate thousands of entities, like the members of a crowd or particles.
+//!
+//! In exchange, a batch tween can't be chained into a [`Sequence`], doesn't invoke a completion
+//! callback, and doesn't send a [`TweenCompleted`] event. Use [`BatchTween::is_completed()`] to
+//! detect its completion.
+//!
+//! The system ticking the batch tweens of a component `T` animated through a lens `L` is
+//! registered with [`TweeningAppExt::register_batch_tween::<T, L>()`].
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_tweening::{lens::*, *};
+//! # use std::time::Duration;
+//! type BatchPosition = BatchTween<Transform, TransformPositionLens>;
+//!
+//! fn setup(mut commands: Commands) {
+//!     for i in 0..10_000 {
+//!         let start = Vec3::new(i as f32, 0., 0.);
+//!         commands
+//!             .spawn_bundle(TransformBundle::default())
+//!             .insert(BatchPosition::new(
+//!                 EaseFunction::QuadraticInOut,
+//!                 TweeningType::PingPong,
+//!                 Duration::from_secs(1),
+//!                 TransformPositionLens {
+//!                     start,
+//!                     end: start + Vec3::Y,
+//!                 },
+//!             ));
+//!     }
+//! }
+//!
+//! App::new()
+//!     .add_plugin(TweeningPlugin::default())
+//!     .register_batch_tween::<Transform, TransformPositionLens>()
+//!     .add_startup_system(setup);
+//! ```
+//!
+//! [`Animator`]: crate::Animator
+//! [`ComputeTaskPool`]: https://docs.rs/bevy/0.7.0/bevy/tasks/struct.ComputeTaskPool.html
+//! [`batch_tween_system::<T, L>`]: crate::batch_tween_system
+//! [`Sequence`]: crate::Sequence
+//! [`TweenCompleted`]: crate::TweenCompleted
+//! [`TweeningAppExt::register_batch_tween::<T, L>()`]: crate::TweeningAppExt::register_batch_tween
+
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::{
+    plugin::record_animator_type, AnimatorState, EaseMethod, Lens, Targetable, TweeningDirection,
+    TweeningType,
+};
+
+/// Plain-data tween animating the component `T` of its entity through a lens of type `L`.
+///
+/// See the [module documentation](crate::batch) for details.
+#[derive(Component)]
+pub struct BatchTween<T: Component, L: Lens<T> + Copy + Send + Sync + 'static> {
+    /// Control if this animation is played or not.
+    pub state: AnimatorState,
+    lens: L,
+    ease_function: EaseMethod,
+    tweening_type: TweeningType,
+    direction: TweeningDirection,
+    duration: f32,
+    elapsed: f32,
+    speed: f32,
+    last_factor: Option<f32>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Component, L: Lens<T> + Copy + Send + Sync + 'static> Clone for BatchTween<T, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Component, L: Lens<T> + Copy + Send + Sync + 'static> Copy for BatchTween<T, L> {}
+
+impl<T: Component, L: Lens<T> + Copy + Send + Sync + 'static> BatchTween<T, L> {
+    /// Create a new batch tween.
+    ///
+    /// The parameters have the same meaning as the ones of [`Tween::new()`].
+    ///
+    /// [`Tween::new()`]: crate::Tween::new
+    pub fn new(
+        ease_function: impl Into<EaseMethod>,
+        tweening_type: TweeningType,
+        duration: Duration,
+        lens: L,
+    ) -> Self {
+        record_animator_type::<Self>();
+        BatchTween {
+            state: AnimatorState::Playing,
+            lens,
+            ease_function: ease_function.into(),
+            tweening_type,
+            direction: TweeningDirection::Forward,
+            duration: duration.as_secs_f32(),
+            elapsed: 0.,
+            speed: 1.,
+            last_factor: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Set the playback direction of the tween.
+    ///
+    /// See [`Tween::set_direction()`] for details.
+    ///
+    /// [`Tween::set_direction()`]: crate::Tween::set_direction
+    pub fn with_direction(mut self, direction: TweeningDirection) -> Self {
+        self.set_direction(direction);
+        self
+    }
+
+    /// Set the playback direction of the tween.
+    ///
+    /// See [`Tween::set_direction()`] for details.
+    ///
+    /// [`Tween::set_direction()`]: crate::Tween::set_direction
+    pub fn set_direction(&mut self, direction: TweeningDirection) {
+        self.direction = direction;
+        self.last_factor = None;
+    }
+
+    /// The current playback direction of the tween.
+    pub fn direction(&self) -> TweeningDirection {
+        self.direction
+    }
+
+    /// Set the playback speed of the tween.
+    ///
+    /// See [`Tweenable::set_speed()`] for details.
+    ///
+    /// [`Tweenable::set_speed()`]: crate::Tweenable::set_speed
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+
+    /// The current playback speed of the tween.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Get the duration of a single iteration of the tween.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f32(self.duration)
+    }
+
+    /// Seek the tween to an absolute time since its start.
+    ///
+    /// For non-looping tweens, the time is clamped to the total duration of the tween. The new
+    /// state is written to the component on the next tick.
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.as_secs_f32();
+        self.wrap_elapsed();
+        self.last_factor = None;
+    }
+
+    /// Get the time elapsed since the start of the tween.
+    ///
+    /// For infinitely looping tweens, this is the time since the start of the current cycle.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_secs_f32(self.elapsed)
+    }
+
+    /// Get the progress of the current iteration of the tween, between `0` and `1`.
+    ///
+    /// See [`Tweenable::progress()`] for details.
+    ///
+    /// [`Tweenable::progress()`]: crate::Tweenable::progress
+    pub fn progress(&self) -> f32 {
+        if self.is_completed() || self.duration <= 0. {
+            1.
+        } else {
+            (self.elapsed / self.duration).fract()
+        }
+    }
+
+    /// Rewind the tween to its starting state.
+    pub fn rewind(&mut self) {
+        self.elapsed = 0.;
+        self.last_factor = None;
+    }
+
+    /// Check if the tween completed all its iterations. Infinitely looping tweens never complete.
+    pub fn is_completed(&self) -> bool {
+        matches!(self.total_duration(), Some(total) if self.elapsed >= total)
+    }
+
+    /// Check if ticking the tween would have no effect, because it's paused, or frozen in a
+    /// state already written to the component.
+    ///
+    /// See [`Tweenable::is_idle()`] for details.
+    ///
+    /// [`Tweenable::is_idle()`]: crate::Tweenable::is_idle
+    pub fn is_idle(&self) -> bool {
+        if self.state == AnimatorState::Paused {
+            return true;
+        }
+        let frozen = if self.speed > 0. {
+            self.is_completed()
+        } else if self.speed < 0. {
+            self.total_duration().is_some() && self.elapsed <= 0.
+        } else {
+            true
+        };
+        frozen && self.last_factor == Some(self.factor())
+    }
+
+    /// Advance the tween by `delta` seconds, and write its new state to the component if it
+    /// changed.
+    pub(crate) fn tick(&mut self, delta: f32, target: &mut impl Targetable<T>) {
+        self.elapsed += delta * self.speed;
+        self.wrap_elapsed();
+        let factor = self.factor();
+        if self.last_factor != Some(factor) {
+            self.lens.lerp(target.target_mut(), factor);
+            self.last_factor = Some(factor);
+        }
+    }
+
+    /// Total duration of all iterations, or `None` if the tween loops indefinitely.
+    fn total_duration(&self) -> Option<f32> {
+        match self.tweening_type {
+            TweeningType::Once => Some(self.duration),
+            TweeningType::LoopTimes(times) | TweeningType::PingPongTimes(times) => {
+                Some(self.duration * times as f32)
+            }
+            TweeningType::Loop | TweeningType::PingPong => None,
+        }
+    }
+
+    /// Clamp the elapsed time to the tween duration, or wrap it into the current cycle.
+    fn wrap_elapsed(&mut self) {
+        match self.tweening_type {
+            TweeningType::Loop if self.duration > 0. => {
+                self.elapsed = self.elapsed.rem_euclid(self.duration);
+            }
+            TweeningType::PingPong if self.duration > 0. => {
+                self.elapsed = self.elapsed.rem_euclid(self.duration * 2.);
+            }
+            _ => {
+                let total = self.total_duration().unwrap_or(0.);
+                self.elapsed = self.elapsed.clamp(0., total);
+            }
+        }
+    }
+
+    /// Eased interpolation factor for the current elapsed time.
+    fn factor(&self) -> f32 {
+        let mut factor = if self.is_completed() || self.duration <= 0. {
+            // End of the last iteration; ping-pong tweens end at their start after an even count
+            match self.tweening_type {
+                TweeningType::PingPongTimes(times) if times % 2 == 0 => 0.,
+                _ => 1.,
+            }
+        } else {
+            let cycle = self.elapsed / self.duration;
+            let progress = cycle.fract();
+            let is_pong = cycle as u32 % 2 == 1;
+            match self.tweening_type {
+                TweeningType::PingPong | TweeningType::PingPongTimes(_) if is_pong => 1. - progress,
+                _ => progress,
+            }
+        };
+        if self.direction.is_backward() {
+            factor = 1. - factor;
+        }
+        self.ease_function.sample(factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lens::TransformPositionLens;
+
+    use super::*;
+
+    type BatchPosition = BatchTween<Transform, TransformPositionLens>;
+
+    fn batch_tween(tweening_type: TweeningType) -> BatchPosition {
+        BatchTween::new(
+            EaseMethod::Linear,
+            tweening_type,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+    }
+
+    /// Tick a batch tween, returning the position it wrote or `None` if it wrote nothing.
+    fn tick(tween: &mut BatchPosition, secs: f32) -> Option<f32> {
+        let mut transform = Transform::from_xyz(-1., 0., 0.);
+        tween.tick(secs, &mut transform);
+        let x = transform.translation.x;
+        if x < 0. {
+            None
+        } else {
+            Some(x)
+        }
+    }
+
+    #[test]
+    fn batch_tween_tick() {
+        for (tweening_type, expected) in [
+            (TweeningType::Once, [0.4, 0.8, 1., 1., 1.]),
+            (TweeningType::Loop, [0.4, 0.8, 0.2, 0.6, 0.]),
+            (TweeningType::LoopTimes(2), [0.4, 0.8, 0.2, 0.6, 1.]),
+            (TweeningType::PingPong, [0.4, 0.8, 0.8, 0.4, 0.]),
+            (TweeningType::PingPongTimes(2), [0.4, 0.8, 0.8, 0.4, 0.]),
+            (TweeningType::PingPongTimes(3), [0.4, 0.8, 0.8, 0.4, 0.]),
+        ] {
+            let mut tween = batch_tween(tweening_type);
+            for (i, expected) in expected.iter().enumerate() {
+                let x = tick(&mut tween, 0.4).unwrap_or_else(|| {
+                    // Nothing written only when the factor didn't change
+                    assert!(tween.is_idle());
+                    *expected
+                });
+                assert!(
+                    (x - expected).abs() < 1e-5,
+                    "{:?} #{}: {}",
+                    tweening_type,
+                    i,
+                    x
+                );
+            }
+            assert_eq!(
+                tween.is_completed(),
+                matches!(
+                    tweening_type,
+                    TweeningType::Once
+                        | TweeningType::LoopTimes(2)
+                        | TweeningType::PingPongTimes(2)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn batch_tween_idle() {
+        let mut tween = batch_tween(TweeningType::Once).with_direction(TweeningDirection::Backward);
+        assert!(!tween.is_idle());
+        assert_eq!(tick(&mut tween, 0.25), Some(0.75));
+        assert_eq!(tick(&mut tween, 1.), Some(0.));
+        assert!(tween.is_completed());
+        assert!(tween.is_idle());
+        assert_eq!(tick(&mut tween, 1.), None);
+
+        // Seeking writes the new state on next tick
+        tween.set_elapsed(Duration::from_secs_f32(0.5));
+        assert!(!tween.is_idle());
+        assert!(!tween.is_completed());
+        assert_eq!(tick(&mut tween, 0.), Some(0.5));
+
+        // Frozen or paused tweens are idle once their current state is written
+        tween.set_speed(0.);
+        assert!(tween.is_idle());
+        tween.set_speed(-1.);
+        assert!(!tween.is_idle());
+        assert_eq!(tick(&mut tween, 1.), Some(1.));
+        assert!(tween.is_idle());
+        tween.set_speed(1.);
+        tween.state = AnimatorState::Paused;
+        assert!(tween.is_idle());
+
+        tween.rewind();
+        assert_eq!(tween.elapsed(), Duration::ZERO);
+        assert!((tween.progress()).abs() < 1e-5);
+    }
+}
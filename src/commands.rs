@@ -0,0 +1,327 @@
+//! Extension of [`EntityCommands`] to animate common components in a single line.
+//!
+//! The [`TweeningCommandsExt`] methods insert on an entity an [`Animator`] playing a single
+//! [`Tween`] with one of the predefined lenses, replacing any animator of the same component
+//! type already present. The tween starts from the current value of the animated component
+//! when the entity has one, so it can be used for quick gameplay feedback without having to
+//! query that value first.
+//!
+//! [`TweeningCommandsExt::tween()`] instead plays any tweenable, and returns a [`TweenFuture`] to
+//! await its completion.
+
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{
+    ecs::system::{Command, EntityCommands},
+    prelude::*,
+};
+
+use crate::{
+    lens::*, Animator, EaseMethod, Lens, NotifyCompletion, Tween, TweenFuture, Tweenable,
+    TweeningType,
+};
+
+/// Extension trait for [`EntityCommands`], to animate common components of an entity with a
+/// single call.
+///
+/// Each method inserts an [`Animator`] playing a [`TweeningType::Once`] tween toward the given
+/// value. The start value is read from the component of the entity when the command is applied,
+/// or is the default value of that component if the entity doesn't have it yet. Inserting the
+/// animator replaces any other [`Animator`] for the same component type on that entity, so
+/// calls animating the same component don't combine: only the last one plays. Calls animating
+/// different components, like a translation and a sprite fade, play together.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// # use std::time::Duration;
+/// fn vanish(mut commands: Commands, query: Query<Entity, With<Sprite>>) {
+///     for entity in query.iter() {
+///         let duration = Duration::from_millis(300);
+///         commands
+///             .entity(entity)
+///             .tween_translation(Vec3::Y * 50., duration, EaseFunction::QuadraticOut)
+///             .fade_sprite(0., duration, EaseMethod::Linear);
+///     }
+/// }
+/// ```
+pub trait TweeningCommandsExt {
+    /// Animate the [`Transform::translation`] of the entity toward `end`.
+    ///
+    /// This inserts an `Animator<Transform>` with a [`TransformPositionLens`], which replaces
+    /// any `Animator<Transform>` already on the entity, even one animating another field like
+    /// the rotation or the scale. That animation stops where it is.
+    fn tween_translation(
+        &mut self,
+        end: Vec3,
+        duration: Duration,
+        ease: impl Into<EaseMethod>,
+    ) -> &mut Self;
+
+    /// Animate the [`Transform::scale`] of the entity toward `end`.
+    ///
+    /// This inserts an `Animator<Transform>` with a [`TransformScaleLens`], which replaces any
+    /// `Animator<Transform>` already on the entity, even one animating another field like the
+    /// translation or the rotation. That animation stops where it is.
+    fn tween_scale(
+        &mut self,
+        end: Vec3,
+        duration: Duration,
+        ease: impl Into<EaseMethod>,
+    ) -> &mut Self;
+
+    /// Fade the [`Sprite::color`] of the entity toward the given `alpha` value, keeping its
+    /// other color components unchanged.
+    ///
+    /// This inserts an `Animator<Sprite>` with a [`SpriteColorLens`].
+    #[cfg(feature = "bevy_sprite")]
+    fn fade_sprite(
+        &mut self,
+        alpha: f32,
+        duration: Duration,
+        ease: impl Into<EaseMethod>,
+    ) -> &mut Self;
+
+    /// Animate a component of the entity with any tweenable, and return a future resolving when
+    /// that tweenable completes.
+    ///
+    /// This inserts an `Animator<T>` playing the tweenable wrapped in a [`NotifyCompletion`]. The
+    /// returned [`TweenFuture`] resolves with [`TweenOutcome::Cancelled`] if the animator is
+    /// removed or replaced, or the entity despawned, before the tweenable completes.
+    ///
+    /// [`TweenOutcome::Cancelled`]: crate::TweenOutcome::Cancelled
+    fn tween<T: Component>(
+        &mut self,
+        tweenable: impl Tweenable<T> + Clone + 'static,
+    ) -> TweenFuture;
+}
+
+impl TweeningCommandsExt for EntityCommands<'_, '_, '_> {
+    fn tween_translation(
+        &mut self,
+        end: Vec3,
+        duration: Duration,
+        ease: impl Into<EaseMethod>,
+    ) -> &mut Self {
+        insert_tween(
+            self,
+            duration,
+            ease,
+            move |transform: Option<&Transform>| TransformPositionLens {
+                start: transform.map_or(Vec3::ZERO, |transform| transform.translation),
+                end,
+            },
+        );
+        self
+    }
+
+    fn tween_scale(
+        &mut self,
+        end: Vec3,
+        duration: Duration,
+        ease: impl Into<EaseMethod>,
+    ) -> &mut Self {
+        insert_tween(
+            self,
+            duration,
+            ease,
+            move |transform: Option<&Transform>| TransformScaleLens {
+                start: transform.map_or(Vec3::ONE, |transform| transform.scale),
+                end,
+            },
+        );
+        self
+    }
+
+    #[cfg(feature = "bevy_sprite")]
+    fn fade_sprite(
+        &mut self,
+        alpha: f32,
+        duration: Duration,
+        ease: impl Into<EaseMethod>,
+    ) -> &mut Self {
+        insert_tween(self, duration, ease, move |sprite: Option<&Sprite>| {
+            let start = sprite.map_or(Color::WHITE, |sprite| sprite.color);
+            let mut end = start;
+            end.set_a(alpha);
+            SpriteColorLens { start, end }
+        });
+        self
+    }
+
+    fn tween<T: Component>(
+        &mut self,
+        tweenable: impl Tweenable<T> + Clone + 'static,
+    ) -> TweenFuture {
+        let (tweenable, future) = NotifyCompletion::new(tweenable);
+        self.insert(Animator::new(tweenable));
+        future
+    }
+}
+
+/// Queue an [`InsertTween`] command for the entity.
+fn insert_tween<T, L, F>(
+    entity_commands: &mut EntityCommands,
+    duration: Duration,
+    ease: impl Into<EaseMethod>,
+    make_lens: F,
+) where
+    T: Component,
+    L: Lens<T> + Send + Sync + 'static,
+    F: FnOnce(Option<&T>) -> L + Send + Sync + 'static,
+{
+    let entity = entity_commands.id();
+    entity_commands.commands().add(InsertTween {
+        entity,
+        duration,
+        ease: ease.into(),
+        make_lens,
+        marker: PhantomData,
+    });
+}
+
+/// Command inserting an [`Animator`] playing a single tween, whose lens is built from the
+/// current value of the animated component.
+struct InsertTween<T, F> {
+    entity: Entity,
+    duration: Duration,
+    ease: EaseMethod,
+    make_lens: F,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, L, F> Command for InsertTween<T, F>
+where
+    T: Component,
+    L: Lens<T> + Send + Sync + 'static,
+    F: FnOnce(Option<&T>) -> L + Send + Sync + 'static,
+{
+    fn write(self, world: &mut World) {
+        let mut entity = world.entity_mut(self.entity);
+        let lens = (self.make_lens)(entity.get::<T>());
+        let tween = Tween::new(self.ease, TweeningType::Once, self.duration, lens);
+        entity.insert(Animator::new(tween));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::CommandQueue;
+
+    use super::*;
+    use crate::{EaseFunction, TweenOutcome, TweeningAppExt};
+
+    /// Apply the commands queued by `f` for a new entity spawned with `bundle`, and return the
+    /// start and end states of the animator inserted on it.
+    fn apply<T: Component + Default>(
+        bundle: impl Bundle,
+        f: impl FnOnce(&mut EntityCommands),
+    ) -> (T, T) {
+        let mut world = World::new();
+        let entity = world.spawn().insert_bundle(bundle).id();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        f(&mut commands.entity(entity));
+        queue.apply(&mut world);
+
+        let animator = world.get::<Animator<T>>(entity).unwrap();
+        let tweenable = animator.tweenable().unwrap();
+        let (mut start, mut end) = (T::default(), T::default());
+        tweenable.sample_at(Duration::ZERO, &mut start);
+        tweenable.sample_at(tweenable.duration(), &mut end);
+        (start, end)
+    }
+
+    #[test]
+    fn tween_translation() {
+        let transform = Transform::from_xyz(1., 2., 3.);
+        let (start, end) = apply::<Transform>((transform,), |entity| {
+            entity.tween_translation(Vec3::X, Duration::from_secs(1), EaseMethod::Linear);
+        });
+        assert!(start.translation.abs_diff_eq(Vec3::new(1., 2., 3.), 1e-5));
+        assert!(end.translation.abs_diff_eq(Vec3::X, 1e-5));
+
+        // Without a Transform, start from the default translation
+        let (start, _) = apply::<Transform>((), |entity| {
+            entity.tween_translation(Vec3::X, Duration::from_secs(1), EaseMethod::Linear);
+        });
+        assert!(start.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+    }
+
+    #[test]
+    fn tween_scale_replaces_animator() {
+        let transform = Transform::from_scale(Vec3::splat(2.));
+        let (start, end) = apply::<Transform>((transform,), |entity| {
+            entity
+                .tween_translation(Vec3::X, Duration::from_secs(1), EaseMethod::Linear)
+                .tween_scale(
+                    Vec3::splat(3.),
+                    Duration::from_secs(1),
+                    EaseFunction::QuadraticIn,
+                );
+        });
+        assert!(start.scale.abs_diff_eq(Vec3::splat(2.), 1e-5));
+        assert!(end.scale.abs_diff_eq(Vec3::splat(3.), 1e-5));
+        // The scale animator replaced the translation one
+        assert!(end.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+    }
+
+    #[test]
+    fn tween_future() {
+        let mut app = App::new();
+        app.insert_resource(Time::default())
+            .register_tweenable_component::<Transform>();
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_nanos(1),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            },
+        );
+        let completed = spawn_tween(&mut app, tween.clone());
+        let cancelled = spawn_tween(&mut app, tween);
+        assert_eq!(completed.1.outcome(), None);
+
+        // Despawning the entity drops the animator before the tween completes
+        app.world.despawn(cancelled.0);
+        assert_eq!(cancelled.1.outcome(), Some(TweenOutcome::Cancelled));
+
+        let mut time = app.world.get_resource_mut::<Time>().unwrap();
+        time.update();
+        std::thread::sleep(Duration::from_millis(1));
+        time.update();
+        app.update();
+        assert_eq!(completed.1.outcome(), Some(TweenOutcome::Completed));
+        let transform = app.world.get::<Transform>(completed.0).unwrap();
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(2.), 1e-5));
+    }
+
+    /// Spawn an entity playing `tween` through [`TweeningCommandsExt::tween()`].
+    fn spawn_tween(app: &mut App, tween: Tween<Transform>) -> (Entity, TweenFuture) {
+        let entity = app.world.spawn().insert(Transform::default()).id();
+        let mut queue = CommandQueue::default();
+        let future = Commands::new(&mut queue, &app.world)
+            .entity(entity)
+            .tween(tween);
+        queue.apply(&mut app.world);
+        (entity, future)
+    }
+
+    #[cfg(feature = "bevy_sprite")]
+    #[test]
+    fn fade_sprite() {
+        let sprite = Sprite {
+            color: Color::rgba(1., 0.5, 0., 0.8),
+            ..Default::default()
+        };
+        let (start, end) = apply::<Sprite>((sprite,), |entity| {
+            entity.fade_sprite(0., Duration::from_secs(1), EaseFunction::QuadraticIn);
+        });
+        assert_eq!(start.color, Color::rgba(1., 0.5, 0., 0.8));
+        assert_eq!(end.color, Color::rgba(1., 0.5, 0., 0.));
+    }
+}
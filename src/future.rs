@@ -0,0 +1,337 @@
+//! Futures resolving when a tweenable completes, to `await` animations from async tasks.
+//!
+//! A [`NotifyCompletion`] wraps any tweenable, and resolves its associated [`TweenFuture`] the
+//! first time the tweenable returns [`TweenState::Completed`] when ticked forward, that is when it
+//! reaches its end. A tweenable playing backward with a negative speed also completes when it
+//! reaches its start, which doesn't resolve the future. If the tweenable is dropped before
+//! reaching its end, for example because its [`Animator`] was removed, replaced, or its entity
+//! despawned, the future resolves with [`TweenOutcome::Cancelled`] instead.
+//!
+//! The future doesn't depend on any executor, so it can be awaited from a task spawned on one of
+//! the `bevy::tasks` pools, or polled from a system with [`TweenFuture::outcome()`].
+//!
+//! [`Animator`]: crate::Animator
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use crate::{CompletionSink, Targetable, TweenState, Tweenable};
+
+/// Outcome of a tweenable awaited through a [`TweenFuture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TweenOutcome {
+    /// The tweenable played until its end.
+    Completed,
+    /// The tweenable was dropped before it completed.
+    Cancelled,
+}
+
+#[derive(Default)]
+struct Shared {
+    outcome: Option<TweenOutcome>,
+    waker: Option<Waker>,
+}
+
+/// Sending side of a [`TweenFuture`], cancelling it when dropped unresolved.
+struct Sender {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl Sender {
+    fn resolve(&self, outcome: TweenOutcome) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap();
+            if shared.outcome.is_some() {
+                return;
+            }
+            shared.outcome = Some(outcome);
+            shared.waker.take()
+        };
+        // Wake the task without holding the lock, in case it's polled immediately
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for Sender {
+    fn drop(&mut self) {
+        self.resolve(TweenOutcome::Cancelled);
+    }
+}
+
+/// Future resolving when the tweenable wrapped in the associated [`NotifyCompletion`] completes,
+/// or is dropped.
+///
+/// Looping tweenables never complete, so their future only resolves once they're dropped.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::{prelude::*, tasks::AsyncComputeTaskPool};
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// fn jump(
+///     mut commands: Commands,
+///     pool: Res<AsyncComputeTaskPool>,
+///     player: Query<Entity, With<Sprite>>,
+/// ) {
+///     let tween = Tween::new(
+///         EaseFunction::QuadraticOut,
+///         TweeningType::Once,
+///         Duration::from_millis(300),
+///         TransformPositionLens {
+///             start: Vec3::ZERO,
+///             end: Vec3::Y * 50.,
+///         },
+///     );
+///     let landed = commands.entity(player.single()).tween(tween);
+///     pool.spawn(async move {
+///         if landed.await == TweenOutcome::Completed {
+///             // [...]
+///         }
+///     })
+///     .detach();
+/// }
+/// ```
+pub struct TweenFuture {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl TweenFuture {
+    /// Get the outcome of the tweenable, if already known, without blocking.
+    ///
+    /// This allows polling the future from a system, without any async executor.
+    pub fn outcome(&self) -> Option<TweenOutcome> {
+        self.shared.lock().unwrap().outcome
+    }
+}
+
+impl Future for TweenFuture {
+    type Output = TweenOutcome;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<TweenOutcome> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.outcome {
+            Some(outcome) => Poll::Ready(outcome),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for TweenFuture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TweenFuture")
+            .field("outcome", &self.outcome())
+            .finish()
+    }
+}
+
+/// Tweenable wrapper resolving a [`TweenFuture`] when the wrapped tweenable completes, playing
+/// forward.
+///
+/// Clones of a `NotifyCompletion` share the same future, which resolves when any of them
+/// completes, or once all of them are dropped.
+#[derive(Clone)]
+pub struct NotifyCompletion<U> {
+    tweenable: U,
+    sender: Arc<Sender>,
+}
+
+impl<U> NotifyCompletion<U> {
+    /// Wrap a tweenable, and return the future resolving when it completes.
+    pub fn new(tweenable: U) -> (Self, TweenFuture) {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let notify = NotifyCompletion {
+            tweenable,
+            sender: Arc::new(Sender {
+                shared: Arc::clone(&shared),
+            }),
+        };
+        (notify, TweenFuture { shared })
+    }
+
+    /// Get the wrapped tweenable.
+    pub fn tweenable(&self) -> &U {
+        &self.tweenable
+    }
+
+    /// Get the wrapped tweenable mutably.
+    pub fn tweenable_mut(&mut self) -> &mut U {
+        &mut self.tweenable
+    }
+}
+
+impl<T, U: Tweenable<T> + Clone + 'static> Tweenable<T> for NotifyCompletion<U> {
+    fn duration(&self) -> Duration {
+        self.tweenable.duration()
+    }
+    fn set_speed(&mut self, speed: f32) {
+        self.tweenable.set_speed(speed);
+    }
+    fn speed(&self) -> f32 {
+        self.tweenable.speed()
+    }
+    fn is_looping(&self) -> bool {
+        self.tweenable.is_looping()
+    }
+    fn set_progress(&mut self, progress: f32) {
+        self.tweenable.set_progress(progress);
+    }
+    fn progress(&self) -> f32 {
+        self.tweenable.progress()
+    }
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.tweenable.set_elapsed(elapsed);
+    }
+    fn elapsed(&self) -> Duration {
+        self.tweenable.elapsed()
+    }
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        completions: &mut dyn CompletionSink,
+    ) -> TweenState {
+        let state = self.tweenable.tick(delta, target, completions);
+        // Playing backward completes at the start, which is not the end awaited
+        if state == TweenState::Completed && self.tweenable.speed() >= 0. {
+            self.sender.resolve(TweenOutcome::Completed);
+        }
+        state
+    }
+    fn apply(&mut self, target: &mut T) {
+        self.tweenable.apply(target);
+    }
+    fn sample_at(&self, elapsed: Duration, target: &mut T) {
+        self.tweenable.sample_at(elapsed, target);
+    }
+    fn is_idle(&self) -> bool {
+        self.tweenable.is_idle()
+    }
+    fn times_completed(&self) -> u32 {
+        self.tweenable.times_completed()
+    }
+    fn rewind(&mut self) {
+        self.tweenable.rewind();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Wake;
+
+    use bevy::{prelude::*, tasks::TaskPool};
+
+    use super::*;
+    use crate::{lens::*, EaseMethod, Tween, TweeningType};
+
+    #[derive(Default)]
+    struct CountWaker(Mutex<u32>);
+
+    impl Wake for CountWaker {
+        fn wake(self: Arc<Self>) {
+            *self.0.lock().unwrap() += 1;
+        }
+    }
+
+    fn tween(tweening_type: TweeningType) -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            tweening_type,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+    }
+
+    fn poll(future: &mut TweenFuture, waker: &Arc<CountWaker>) -> Poll<TweenOutcome> {
+        let waker = Waker::from(Arc::clone(waker));
+        Pin::new(future).poll(&mut Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn completed() {
+        let (mut notify, mut future) = NotifyCompletion::new(tween(TweeningType::Once));
+        let waker = Arc::new(CountWaker::default());
+        assert_eq!(poll(&mut future, &waker), Poll::Pending);
+        assert_eq!(future.outcome(), None);
+
+        let mut transform = Transform::default();
+        notify.tick(Duration::from_millis(500), &mut transform, &mut ());
+        assert_eq!(future.outcome(), None);
+        assert_eq!(*waker.0.lock().unwrap(), 0);
+
+        let state = notify.tick(Duration::from_millis(500), &mut transform, &mut ());
+        assert_eq!(state, TweenState::Completed);
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert_eq!(*waker.0.lock().unwrap(), 1);
+        assert_eq!(
+            poll(&mut future, &waker),
+            Poll::Ready(TweenOutcome::Completed)
+        );
+
+        // Dropping the completed tweenable doesn't change the outcome
+        drop(notify);
+        assert_eq!(future.outcome(), Some(TweenOutcome::Completed));
+        assert_eq!(*waker.0.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn completed_backward() {
+        let (mut notify, future) = NotifyCompletion::new(tween(TweeningType::Once));
+        let mut transform = Transform::default();
+        notify.tick(Duration::from_millis(500), &mut transform, &mut ());
+
+        // Rewinding to the start doesn't resolve the future
+        notify.set_speed(-1.);
+        let state = notify.tick(Duration::from_secs(1), &mut transform, &mut ());
+        assert_eq!(state, TweenState::Completed);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert_eq!(future.outcome(), None);
+
+        // Reaching the end when playing forward again does
+        notify.set_speed(1.);
+        let state = notify.tick(Duration::from_secs(1), &mut transform, &mut ());
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(future.outcome(), Some(TweenOutcome::Completed));
+    }
+
+    #[test]
+    fn cancelled() {
+        let (notify, mut future) = NotifyCompletion::new(tween(TweeningType::Loop));
+        let clone = notify.clone();
+        let waker = Arc::new(CountWaker::default());
+        assert_eq!(poll(&mut future, &waker), Poll::Pending);
+
+        // Cancelled once all the clones are dropped
+        drop(notify);
+        assert_eq!(future.outcome(), None);
+        drop(clone);
+        assert_eq!(*waker.0.lock().unwrap(), 1);
+        assert_eq!(
+            poll(&mut future, &waker),
+            Poll::Ready(TweenOutcome::Cancelled)
+        );
+    }
+
+    #[test]
+    fn task_pool() {
+        let (mut notify, future) = NotifyCompletion::new(tween(TweeningType::Once));
+        notify.tick(Duration::from_secs(2), &mut Transform::default(), &mut ());
+
+        let pool = TaskPool::new();
+        let outcomes = pool.scope(|scope| scope.spawn(future));
+        assert_eq!(outcomes, vec![TweenOutcome::Completed]);
+    }
+}
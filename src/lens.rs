@@ -6,6 +6,18 @@
 //! entirely optional. They can be used if they fit your use case, to save some time,
 //! but are not treated any differently from a custom user-provided lens.
 //!
+//! # Closure and field lenses
+//!
+//! For one-off animations of a custom component or asset, declaring a new lens type
+//! can be avoided by wrapping a closure with [`ClosureLens`], or by using [`lens_fn()`]
+//! to interpolate a value between two endpoints and assign it to the target.
+//!
+//! When the animated value is a single field whose type implements [`Lerp`], a
+//! [`FieldLens`] only needs an accessor function returning a mutable reference to
+//! that field.
+//!
+//! [`Lerp`]: crate::Lerp
+//!
 //! # Rotations
 //!
 //! Several rotation lenses are provided, with different properties.
@@ -36,6 +48,8 @@
 
 use bevy::prelude::*;
 
+use crate::{BoxedLens, Lerp};
+
 /// A lens over a subset of a component.
 ///
 /// The lens takes a `target` component or asset from a query, as a mutable reference,
@@ -49,6 +63,7 @@ use bevy::prelude::*;
 /// ```rust
 /// # use bevy::prelude::*;
 /// # use bevy_tweening::*;
+/// #[derive(Clone)]
 /// struct MyLens {
 ///   start: f32,
 ///   end: f32,
@@ -64,7 +79,7 @@ use bevy::prelude::*;
 /// }
 /// ```
 ///
-pub trait Lens<T> {
+pub trait Lens<T>: CloneLens<T> {
     /// Perform a linear interpolation (lerp) over the subset of fields of a component
     /// or asset the lens focuses on, based on the linear ratio `ratio`. The `target`
     /// component or asset is mutated in place. The implementation decides which fields
@@ -72,6 +87,214 @@ pub trait Lens<T> {
     fn lerp(&mut self, target: &mut T, ratio: f32);
 }
 
+impl<T: 'static> Lens<T> for BoxedLens<T> {
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        self.as_mut().lerp(target, ratio);
+    }
+}
+
+impl<T: 'static, L: Lens<T> + Clone + Send + Sync + 'static> Lens<T> for Box<L> {
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        self.as_mut().lerp(target, ratio);
+    }
+}
+
+/// Clone a lens into a new [`BoxedLens`].
+///
+/// This trait is a supertrait of [`Lens`], and is implemented for all the lenses which are
+/// [`Clone`], [`Send`] and [`Sync`]. It allows cloning a type-erased lens, and therefore any
+/// [`Tween`] holding one. There is no need to implement it manually.
+///
+/// [`Tween`]: crate::Tween
+pub trait CloneLens<T> {
+    /// Clone the lens into a new boxed lens.
+    fn box_clone(&self) -> BoxedLens<T>;
+}
+
+impl<T, L: Lens<T> + Clone + Send + Sync + 'static> CloneLens<T> for L {
+    fn box_clone(&self) -> BoxedLens<T> {
+        Box::new(self.clone())
+    }
+}
+
+impl<T> Clone for BoxedLens<T> {
+    fn clone(&self) -> Self {
+        (**self).box_clone()
+    }
+}
+
+/// A lens wrapping a closure, for one-off animations of custom components or assets.
+///
+/// The closure receives the `target` to animate and the linear ratio in \[0:1\], already
+/// sampled from the easing curve, exactly like [`Lens::lerp()`]. This avoids declaring a
+/// dedicated lens type when the animation is only used in a single place.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// let tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     TweeningType::Once,
+///     Duration::from_secs(1),
+///     ClosureLens::new(|health: &mut Health, ratio| health.0 = 100. * (1. - ratio)),
+/// );
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct ClosureLens<F> {
+    f: F,
+}
+
+impl<F> ClosureLens<F> {
+    /// Create a new lens from a closure invoked with the target and the interpolation ratio.
+    pub fn new(f: F) -> Self {
+        ClosureLens { f }
+    }
+}
+
+impl<T: 'static, F> Lens<T> for ClosureLens<F>
+where
+    F: FnMut(&mut T, f32) + Clone + Send + Sync + 'static,
+{
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        (self.f)(target, ratio);
+    }
+}
+
+/// A lens interpolating a value between two endpoints, and passing it to a closure.
+///
+/// This lens is created with [`lens_fn()`]. The value is interpolated with the [`Lerp`]
+/// trait, so any type implementing it with a scalar of type `f32` can be animated.
+///
+/// [`Lerp`]: crate::Lerp
+#[derive(Debug, Copy, Clone)]
+pub struct LerpClosureLens<V, F> {
+    /// Start value.
+    pub start: V,
+    /// End value.
+    pub end: V,
+    setter: F,
+}
+
+impl<T: 'static, V, F> Lens<T> for LerpClosureLens<V, F>
+where
+    V: Lerp<Scalar = f32> + Clone + Send + Sync + 'static,
+    F: FnMut(&mut T, V) + Clone + Send + Sync + 'static,
+{
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        let value = self.start.lerp(&self.end, &ratio);
+        (self.setter)(target, value);
+    }
+}
+
+/// Create a lens interpolating a value from `start` to `end`, and assigning it with a closure.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// #[derive(Component)]
+/// struct HealthBar {
+///     fill: f32,
+/// }
+///
+/// let tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     TweeningType::Once,
+///     Duration::from_secs(1),
+///     lens_fn(1., 0.25, |bar: &mut HealthBar, fill| bar.fill = fill),
+/// );
+/// ```
+pub fn lens_fn<T, V, F>(start: V, end: V, setter: F) -> LerpClosureLens<V, F>
+where
+    V: Lerp<Scalar = f32>,
+    F: FnMut(&mut T, V),
+{
+    LerpClosureLens { start, end, setter }
+}
+
+/// A lens over a single field of a component or asset, accessed through a function.
+///
+/// The field value is interpolated from `start` to `end` with the [`Lerp`] trait, then written
+/// through the `accessor` function returning a mutable reference to the field. This allows
+/// animating any field whose type implements [`Lerp`] without writing a dedicated lens type.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// #[derive(Component)]
+/// struct HealthBar {
+///     fill: f32,
+/// }
+///
+/// let tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     TweeningType::Once,
+///     Duration::from_secs(1),
+///     FieldLens::new(1., 0.25, |bar: &mut HealthBar| &mut bar.fill),
+/// );
+/// ```
+///
+/// [`Lerp`]: crate::Lerp
+pub struct FieldLens<T, V> {
+    /// Start value of the field.
+    pub start: V,
+    /// End value of the field.
+    pub end: V,
+    accessor: fn(&mut T) -> &mut V,
+}
+
+impl<T, V> FieldLens<T, V> {
+    /// Create a new lens animating the field returned by `accessor` from `start` to `end`.
+    pub fn new(start: V, end: V, accessor: fn(&mut T) -> &mut V) -> Self {
+        FieldLens {
+            start,
+            end,
+            accessor,
+        }
+    }
+}
+
+impl<T, V: Copy> Copy for FieldLens<T, V> {}
+
+impl<T, V: Clone> Clone for FieldLens<T, V> {
+    fn clone(&self) -> Self {
+        FieldLens {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            accessor: self.accessor,
+        }
+    }
+}
+
+impl<T, V: std::fmt::Debug> std::fmt::Debug for FieldLens<T, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldLens")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+impl<T: 'static, V> Lens<T> for FieldLens<T, V>
+where
+    V: Lerp<Scalar = f32> + Clone + Send + Sync + 'static,
+{
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        *(self.accessor)(target) = self.start.lerp(&self.end, &ratio);
+    }
+}
+
 /// A lens to manipulate the [`color`] field of a section of a [`Text`] component.
 ///
 /// [`color`]: https://docs.rs/bevy/0.7.0/bevy/text/struct.TextStyle.html#structfield.color
@@ -94,7 +317,10 @@ impl Lens<Text> for TextColorLens {
         let start: Vec4 = self.start.into();
         let end: Vec4 = self.end.into();
         let value = start.lerp(end, ratio);
-        target.sections[self.section].style.color = value.into();
+        // Ignore invalid section indices instead of panicking, like the sections were removed
+        if let Some(section) = target.sections.get_mut(self.section) {
+            section.style.color = value.into();
+        }
     }
 }
 
@@ -362,6 +588,65 @@ mod tests {
     use super::*;
     use std::f32::consts::TAU;
 
+    #[test]
+    fn closure_lens() {
+        let mut lens = ClosureLens::new(|transform: &mut Transform, ratio| {
+            transform.translation.x = ratio * 2.;
+        });
+        let mut transform = Transform::default();
+
+        lens.lerp(&mut transform, 0.);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+
+        lens.lerp(&mut transform, 1.);
+        assert!(transform.translation.abs_diff_eq(Vec3::X * 2., 1e-5));
+
+        lens.lerp(&mut transform, 0.3);
+        assert!(transform.translation.abs_diff_eq(Vec3::X * 0.6, 1e-5));
+        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+    }
+
+    #[test]
+    fn closure_lens_fn() {
+        let mut lens = lens_fn(1., -4., |transform: &mut Transform, y| {
+            transform.translation.y = y;
+        });
+        let mut transform = Transform::default();
+
+        lens.lerp(&mut transform, 0.);
+        assert!(transform.translation.abs_diff_eq(Vec3::Y, 1e-5));
+
+        lens.lerp(&mut transform, 1.);
+        assert!(transform.translation.abs_diff_eq(Vec3::Y * -4., 1e-5));
+
+        lens.lerp(&mut transform, 0.3);
+        assert!(transform.translation.abs_diff_eq(Vec3::Y * -0.5, 1e-5));
+        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+    }
+
+    #[test]
+    fn boxed_lens() {
+        use crate::{EaseMethod, Tween, Tweenable, TweeningType};
+        use std::time::Duration;
+
+        // A tween can be generic over a box of a concrete lens, and still be cloned
+        let tween: Tween<Transform, Box<TransformPositionLens>> = Tween::new_static(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            Box::new(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            }),
+        );
+        let mut tween = tween.clone();
+        let mut transform = Transform::default();
+        tween.tick(Duration::from_millis(500), &mut transform, &mut ());
+        assert!(transform.translation.abs_diff_eq(Vec3::X * 0.5, 1e-5));
+    }
+
     #[cfg(feature = "bevy_ui")]
     #[test]
     fn text_color() {
@@ -380,6 +665,32 @@ mod tests {
 
         lens.lerp(&mut text, 0.3);
         assert_eq!(text.sections[0].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
+
+        // An invalid section index leaves the text unchanged
+        let mut lens = TextColorLens { section: 1, ..lens };
+        lens.lerp(&mut text, 1.);
+        assert_eq!(text.sections[0].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
+    }
+
+    #[test]
+    fn field_lens() {
+        let mut lens = FieldLens::new(0., 2., |transform: &mut Transform| {
+            &mut transform.translation.z
+        });
+        let mut transform = Transform::default();
+
+        lens.lerp(&mut transform, 0.);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+
+        lens.lerp(&mut transform, 1.);
+        assert!(transform.translation.abs_diff_eq(Vec3::Z * 2., 1e-5));
+
+        let mut copy = lens;
+        copy.end = 1.;
+        copy.lerp(&mut transform, 0.3);
+        assert!(transform.translation.abs_diff_eq(Vec3::Z * 0.3, 1e-5));
+        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
     }
 
     #[test]
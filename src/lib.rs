@@ -26,7 +26,7 @@
 //!
 //! App::default()
 //!     .add_plugins(DefaultPlugins)
-//!     .add_plugin(TweeningPlugin)
+//!     .add_plugin(TweeningPlugin::default())
 //!     .run();
 //! ```
 //!
@@ -139,6 +139,9 @@
 //! components of a [`Transform`]. A custom lens can also be created by implementing the trait, allowing to animate
 //! virtually any field of any Bevy component or asset.
 //!
+//! With the `derive` feature, lenses for the fields of a custom component or asset can also be
+//! generated with `#[derive(Lens)]`, by annotating each field to animate with `#[tween]`.
+//!
 //! [`Transform::translation`]: https://docs.rs/bevy/0.7.0/bevy/transform/components/struct.Transform.html#structfield.translation
 //! [`Entity`]: https://docs.rs/bevy/0.7.0/bevy/ecs/entity/struct.Entity.html
 //! [`Query`]: https://docs.rs/bevy/0.7.0/bevy/ecs/system/struct.Query.html
@@ -146,21 +149,44 @@
 //! [`Sprite`]: https://docs.rs/bevy/0.7.0/bevy/sprite/struct.Sprite.html
 //! [`Transform`]: https://docs.rs/bevy/0.7.0/bevy/transform/components/struct.Transform.html
 
-use bevy::{asset::Asset, prelude::*};
+use std::{borrow::Cow, marker::PhantomData, time::Duration};
+
+use bevy::{asset::Asset, ecs::system::Resource, prelude::*};
 
 use interpolation::Ease as IEase;
 pub use interpolation::EaseFunction;
 pub use interpolation::Lerp;
 
+pub mod batch;
+mod commands;
+mod future;
 pub mod lens;
 mod plugin;
+pub mod stagger;
 mod tweenable;
 
-pub use lens::Lens;
+pub use batch::BatchTween;
+/// Derive macro generating lens types for the fields of a custom struct.
+///
+/// Available with the `derive` feature.
+#[cfg(feature = "derive")]
+pub use bevy_tweening_derive::Lens;
+pub use commands::TweeningCommandsExt;
+pub use future::{NotifyCompletion, TweenFuture, TweenOutcome};
+pub use lens::{CloneLens, Lens};
 pub use plugin::{
-    asset_animator_system, component_animator_system, AnimationSystem, TweeningPlugin,
+    asset_animator_system, batch_tween_system, component_animator_system,
+    hierarchy_animator_system, multi_animator_system, resource_animator_system,
+    static_component_animator_system, timeline_asset_system, timeline_clock_system,
+    timeline_component_system, AnimationSystem, TweeningAppExt, TweeningPlugin,
+};
+pub use stagger::{Stagger, StaggerFrom};
+pub use tweenable::{
+    BoxedLens, BoxedTweenable, CloneTweenable, CompletedEvents, CompletionSink, Delay, Sequence,
+    Targetable, Tracks, Tween, TweenCompleted, TweenState, Tweenable, TweeningError,
 };
-pub use tweenable::{Delay, Sequence, Tracks, Tween, TweenCompleted, TweenState, Tweenable};
+
+use plugin::record_animator_type;
 
 /// Type of looping for a tween animation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -305,14 +331,22 @@ impl std::ops::Not for TweeningDirection {
 }
 
 /// Component to control the animation of another component.
+///
+/// By default, an animator created with [`Animator::new()`] boxes its tweenable, so animators of
+/// tweenables of different types are all of the same `Animator<T>` type. To avoid that allocation
+/// and the virtual calls it implies, [`Animator::new_static()`] creates an `Animator<T, U>` holding
+/// a tweenable of the concrete type `U` by value. Such animators are ticked by the
+/// [`static_component_animator_system::<T, U>`], registered with
+/// [`TweeningAppExt::register_static_animator()`].
 #[derive(Component)]
-pub struct Animator<T: Component> {
+pub struct Animator<T: Component, U: Tweenable<T> + 'static = BoxedTweenable<T>> {
     /// Control if this animation is played or not.
     pub state: AnimatorState,
-    tweenable: Option<Box<dyn Tweenable<T> + Send + Sync + 'static>>,
+    tweenable: Option<U>,
+    marker: PhantomData<fn() -> T>,
 }
 
-impl<T: Component + std::fmt::Debug> std::fmt::Debug for Animator<T> {
+impl<T: Component + std::fmt::Debug, U: Tweenable<T> + 'static> std::fmt::Debug for Animator<T, U> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Animator")
             .field("state", &self.state)
@@ -320,32 +354,38 @@ impl<T: Component + std::fmt::Debug> std::fmt::Debug for Animator<T> {
     }
 }
 
+impl<T: Component, U: Tweenable<T> + Clone + 'static> Clone for Animator<T, U> {
+    fn clone(&self) -> Self {
+        Animator {
+            state: self.state,
+            tweenable: self.tweenable.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
 impl<T: Component> Default for Animator<T> {
     fn default() -> Self {
+        record_animator_type::<Self>();
         Animator {
             state: Default::default(),
             tweenable: None,
+            marker: PhantomData,
         }
     }
 }
 
 impl<T: Component> Animator<T> {
     /// Create a new animator component from a single tweenable.
-    pub fn new(tween: impl Tweenable<T> + Send + Sync + 'static) -> Self {
+    pub fn new(tween: impl Tweenable<T> + 'static) -> Self {
         Animator {
             tweenable: Some(Box::new(tween)),
             ..Default::default()
         }
     }
 
-    /// Set the initial playback state of the animator.
-    pub fn with_state(mut self, state: AnimatorState) -> Self {
-        self.state = state;
-        self
-    }
-
     /// Set the top-level tweenable item this animator controls.
-    pub fn set_tweenable(&mut self, tween: impl Tweenable<T> + Send + Sync + 'static) {
+    pub fn set_tweenable(&mut self, tween: impl Tweenable<T> + 'static) {
         self.tweenable = Some(Box::new(tween));
     }
 
@@ -366,6 +406,50 @@ impl<T: Component> Animator<T> {
             None
         }
     }
+}
+
+impl<T: Component, U: Tweenable<T> + 'static> Animator<T, U> {
+    /// Create a new animator component holding a tweenable by value, without boxing it.
+    ///
+    /// The animator type depends on the type `U` of the tweenable. Its animator system needs to be
+    /// registered with [`TweeningAppExt::register_static_animator::<T, U>()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use std::time::Duration;
+    /// type PositionTween = Tween<Transform, TransformPositionLens>;
+    ///
+    /// let animator: Animator<Transform, PositionTween> = Animator::new_static(Tween::new_static(
+    ///     EaseFunction::QuadraticInOut,
+    ///     TweeningType::Once,
+    ///     Duration::from_secs(1),
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::X,
+    ///     },
+    /// ));
+    ///
+    /// App::new().register_static_animator::<Transform, PositionTween>();
+    /// ```
+    ///
+    /// [`TweeningAppExt::register_static_animator::<T, U>()`]: TweeningAppExt::register_static_animator
+    pub fn new_static(tweenable: U) -> Self {
+        record_animator_type::<Self>();
+        Animator {
+            state: Default::default(),
+            tweenable: Some(tweenable),
+            marker: PhantomData,
+        }
+    }
+
+    /// Set the initial playback state of the animator.
+    pub fn with_state(mut self, state: AnimatorState) -> Self {
+        self.state = state;
+        self
+    }
 
     /// Set the current animation playback progress.
     ///
@@ -401,6 +485,26 @@ impl<T: Component> Animator<T> {
         }
     }
 
+    /// Set the current elapsed time since the start of the animation.
+    ///
+    /// See [Tweenable::set_elapsed] for details.
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        if let Some(tweenable) = &mut self.tweenable {
+            tweenable.set_elapsed(elapsed)
+        }
+    }
+
+    /// Get the elapsed time since the start of the animation.
+    ///
+    /// See [Tweenable::elapsed] for details.
+    pub fn elapsed(&self) -> Duration {
+        if let Some(tweenable) = &self.tweenable {
+            tweenable.elapsed()
+        } else {
+            Duration::ZERO
+        }
+    }
+
     /// Set the animation speed. See [Tweenable::set_speed] for details.
     pub fn set_speed(&mut self, speed: f32) {
         if let Some(tweenable) = &mut self.tweenable {
@@ -408,6 +512,16 @@ impl<T: Component> Animator<T> {
         }
     }
 
+    /// Get the animation speed. See [Tweenable::set_speed] for details.
+    ///
+    /// This is `1` if the animator has no tweenable.
+    pub fn speed(&self) -> f32 {
+        match &self.tweenable {
+            Some(tweenable) => tweenable.speed(),
+            None => 1.,
+        }
+    }
+
     /// Stop animation playback and rewind the animation.
     ///
     /// This changes the animator state to [`AnimatorState::Paused`] and rewind its tweenable.
@@ -426,13 +540,697 @@ impl<T: Component> Animator<T> {
     }
 }
 
-/// Component to control the animation of an asset.
+/// Component to run several independent animations on the same component.
+///
+/// An entity can only have a single [`Animator<T>`] per component type `T`. A [`MultiAnimator<T>`]
+/// instead holds any number of slots, each identified by a name and controlled by its own
+/// [`Animator<T>`], which can be played, paused, rewound, or replaced independently of the
+/// other slots. This allows for example a looping animation of the translation of a [`Transform`]
+/// and a one-shot animation of its rotation started later by gameplay code.
+///
+/// All playing slots are ticked each frame by the [`multi_animator_system::<T>`], in the order
+/// the slots were first inserted. Slots animating the same fields of the component override each
+/// other, with the last slot winning: whenever a slot writes to the component, all the following
+/// slots which aren't paused write their current state again on top of it, even if they're
+/// completed or their state didn't change.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let bob = Tween::new(
+///     EaseFunction::SineInOut,
+///     TweeningType::PingPong,
+///     Duration::from_secs(1),
+///     TransformPositionLens {
+///         start: Vec3::ZERO,
+///         end: Vec3::Y,
+///     },
+/// );
+/// let mut animator = MultiAnimator::<Transform>::default().with_slot("bob", Animator::new(bob));
+///
+/// // Later, start a spin without affecting the bobbing
+/// let spin = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     TweeningType::Once,
+///     Duration::from_secs(2),
+///     TransformRotateYLens {
+///         start: 0.,
+///         end: std::f32::consts::TAU,
+///     },
+/// );
+/// animator.insert("spin", Animator::new(spin));
+/// ```
+///
+/// [`Transform`]: https://docs.rs/bevy/0.7.0/bevy/transform/components/struct.Transform.html
+/// [`multi_animator_system::<T>`]: crate::multi_animator_system
+#[derive(Component)]
+pub struct MultiAnimator<T: Component> {
+    /// Control if the animations of all slots are played or not.
+    ///
+    /// When [`AnimatorState::Paused`], no slot is ticked, irrespective of its own state.
+    pub state: AnimatorState,
+    slots: Vec<(Cow<'static, str>, Animator<T>)>,
+}
+
+impl<T: Component + std::fmt::Debug> std::fmt::Debug for MultiAnimator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiAnimator")
+            .field("state", &self.state)
+            .field("slots", &self.slots)
+            .finish()
+    }
+}
+
+impl<T: Component> Default for MultiAnimator<T> {
+    fn default() -> Self {
+        record_animator_type::<Self>();
+        MultiAnimator {
+            state: Default::default(),
+            slots: vec![],
+        }
+    }
+}
+
+impl<T: Component> MultiAnimator<T> {
+    /// Set the initial playback state of the animator.
+    pub fn with_state(mut self, state: AnimatorState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Add a slot with the given name and animator, replacing any existing slot with that name.
+    pub fn with_slot(mut self, name: impl Into<Cow<'static, str>>, animator: Animator<T>) -> Self {
+        self.insert(name, animator);
+        self
+    }
+
+    /// Insert a slot with the given name and animator.
+    ///
+    /// If a slot with that name already exists, its animator is replaced and returned, and the
+    /// slot keeps its position in the tick order. Otherwise the slot is added last.
+    pub fn insert(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        animator: Animator<T>,
+    ) -> Option<Animator<T>> {
+        let name = name.into();
+        match self.get_mut(&name) {
+            Some(slot) => Some(std::mem::replace(slot, animator)),
+            None => {
+                self.slots.push((name, animator));
+                None
+            }
+        }
+    }
+
+    /// Remove the slot with the given name, returning its animator if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<Animator<T>> {
+        let index = self.slots.iter().position(|(key, _)| key == name)?;
+        Some(self.slots.remove(index).1)
+    }
+
+    /// Get the animator of the slot with the given name.
+    pub fn get(&self, name: &str) -> Option<&Animator<T>> {
+        self.slots
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, animator)| animator)
+    }
+
+    /// Get the mutable animator of the slot with the given name.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Animator<T>> {
+        self.slots
+            .iter_mut()
+            .find(|(key, _)| key == name)
+            .map(|(_, animator)| animator)
+    }
+
+    /// Does a slot with the given name exist?
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Iterate over the names and animators of all slots, in tick order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Animator<T>)> {
+        self.slots
+            .iter()
+            .map(|(name, animator)| (name.as_ref(), animator))
+    }
+
+    /// Iterate over the names and mutable animators of all slots, in tick order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut Animator<T>)> {
+        self.slots
+            .iter_mut()
+            .map(|(name, animator)| (&**name, animator))
+    }
+
+    /// Number of slots.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Is the animator without any slot?
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Remove all slots.
+    pub fn clear(&mut self) {
+        self.slots.clear();
+    }
+}
+
+/// Target entity of a track of a [`HierarchyAnimator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnimationTarget {
+    /// A specific entity.
+    Entity(Entity),
+    /// A descendant of the entity the animator is attached to, found by following the [`Name`]
+    /// of each entity from that root entity down the hierarchy. An empty path designates the root
+    /// entity itself.
+    ///
+    /// A path can be built from a string by separating the names with a `/`, like
+    /// `"container/button"`.
+    ///
+    /// [`Name`]: https://docs.rs/bevy/0.7.0/bevy/core/struct.Name.html
+    Path(Vec<Name>),
+}
+
+impl From<Entity> for AnimationTarget {
+    fn from(entity: Entity) -> Self {
+        AnimationTarget::Entity(entity)
+    }
+}
+
+impl From<&str> for AnimationTarget {
+    fn from(path: &str) -> Self {
+        AnimationTarget::Path(
+            path.split('/')
+                .filter(|name| !name.is_empty())
+                .map(|name| Name::new(name.to_owned()))
+                .collect(),
+        )
+    }
+}
+
+/// A single track of a [`HierarchyAnimator`], animating a single target entity.
+struct HierarchyTrack<T> {
+    target: AnimationTarget,
+    /// Last entity the target resolved to, if any.
+    entity: Option<Entity>,
+    /// Time elapsed while the target couldn't be resolved, which the track catches up with once
+    /// it resolves.
+    missed: Duration,
+    tweenable: Box<dyn Tweenable<T> + Send + Sync + 'static>,
+}
+
+/// Component to animate a component on several entities of a hierarchy from a single animator.
+///
+/// The animator is attached to a root entity, and holds a collection of tracks each animating
+/// the component `T` of a target entity, designated by its [`Entity`] or by a path of [`Name`]
+/// from the root entity down the hierarchy. All tracks are played in parallel and share the same
+/// clock, like a [`Tracks`], so a whole choreography, like opening a menu made of a container and
+/// its buttons, can be described and controlled from a single place.
+///
+/// Target paths are resolved lazily, on the first frame the track plays, so tracks can target
+/// descendants spawned in the same frame as the animator. Tracks whose target doesn't exist, or
+/// doesn't have a component `T`, are skipped until it does, and then catch up with the time
+/// elapsed meanwhile, so all tracks stay in sync.
+///
+/// The [`TweenCompleted`] events of all tracks report the root entity the animator is attached
+/// to, not the entity of the track.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let scale = |delay_ms| {
+///     Delay::new(Duration::from_millis(delay_ms)).then(Tween::new(
+///         EaseFunction::BounceOut,
+///         TweeningType::Once,
+///         Duration::from_secs(2),
+///         TransformScaleLens {
+///             start: Vec3::splat(0.01),
+///             end: Vec3::ONE,
+///         },
+///     ))
+/// };
+/// let animator = HierarchyAnimator::<Transform>::default()
+///     .with_track("", scale(0))
+///     .with_track("button:continue", scale(500))
+///     .with_track("button:quit", scale(1000));
+/// ```
+///
+/// [`Name`]: https://docs.rs/bevy/0.7.0/bevy/core/struct.Name.html
+/// [`Entity`]: https://docs.rs/bevy/0.7.0/bevy/ecs/entity/struct.Entity.html
+#[derive(Component)]
+pub struct HierarchyAnimator<T: Component> {
+    /// Control if this animation is played or not.
+    pub state: AnimatorState,
+    tracks: Vec<HierarchyTrack<T>>,
+    duration: Duration,
+    elapsed: Duration,
+    speed: f32,
+}
+
+impl<T: Component + std::fmt::Debug> std::fmt::Debug for HierarchyAnimator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HierarchyAnimator")
+            .field("state", &self.state)
+            .field(
+                "targets",
+                &self
+                    .tracks
+                    .iter()
+                    .map(|track| &track.target)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<T: Component> Default for HierarchyAnimator<T> {
+    fn default() -> Self {
+        record_animator_type::<Self>();
+        HierarchyAnimator {
+            state: Default::default(),
+            tracks: vec![],
+            duration: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            speed: 1.,
+        }
+    }
+}
+
+impl<T: Component> HierarchyAnimator<T> {
+    /// Set the initial playback state of the animator.
+    pub fn with_state(mut self, state: AnimatorState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Add a track animating the given target with a tweenable.
+    pub fn with_track(
+        mut self,
+        target: impl Into<AnimationTarget>,
+        tween: impl Tweenable<T> + 'static,
+    ) -> Self {
+        self.add_track(target, tween);
+        self
+    }
+
+    /// Add a track animating the given target with a tweenable.
+    ///
+    /// The track starts at the current playback position of the animator.
+    pub fn add_track(
+        &mut self,
+        target: impl Into<AnimationTarget>,
+        tween: impl Tweenable<T> + 'static,
+    ) {
+        self.duration = self.duration.max(tween.duration());
+        self.tracks.push(HierarchyTrack {
+            target: target.into(),
+            entity: None,
+            missed: Duration::ZERO,
+            tweenable: Box::new(tween),
+        });
+    }
+
+    /// Iterate over the targets of all tracks, in the order they were added.
+    pub fn targets(&self) -> impl Iterator<Item = &AnimationTarget> {
+        self.tracks.iter().map(|track| &track.target)
+    }
+
+    /// Get the total duration of the animation, which is the duration of its longest track.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Set the current animation playback progress.
+    ///
+    /// See [`progress()`] for details on the meaning.
+    ///
+    /// [`progress()`]: HierarchyAnimator::progress
+    pub fn set_progress(&mut self, progress: f32) {
+        self.set_elapsed(self.duration.mul_f32(progress.clamp(0., 1.)));
+    }
+
+    /// Get the current progress in \[0:1\] of the animation.
+    ///
+    /// Like for [`Tracks`], this is the current elapsed time over the duration of the longest
+    /// track.
+    pub fn progress(&self) -> f32 {
+        if self.duration.is_zero() {
+            0.
+        } else {
+            self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        }
+    }
+
+    /// Set the current elapsed time since the start of the animation, for all tracks.
+    ///
+    /// See [Tweenable::set_elapsed] for details.
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.min(self.duration);
+        for track in &mut self.tracks {
+            track.missed = Duration::ZERO;
+            track.tweenable.set_elapsed(self.elapsed);
+        }
+    }
+
+    /// Get the elapsed time since the start of the animation.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Set the animation speed of all tracks. See [Tweenable::set_speed] for details.
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+        for track in &mut self.tracks {
+            track.tweenable.set_speed(speed);
+        }
+    }
+
+    /// Get the animation speed of the tracks. See [Tweenable::set_speed] for details.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Stop animation playback and rewind the animation.
+    ///
+    /// This changes the animator state to [`AnimatorState::Paused`] and rewind all its tracks.
+    pub fn stop(&mut self) {
+        self.state = AnimatorState::Paused;
+        self.rewind();
+    }
+
+    /// Rewind animation playback to its initial state.
+    ///
+    /// This does not change the playback state (playing/paused).
+    pub fn rewind(&mut self) {
+        self.elapsed = Duration::ZERO;
+        for track in &mut self.tracks {
+            track.missed = Duration::ZERO;
+            track.tweenable.rewind();
+        }
+    }
+}
+
+/// Track of a [`Timeline`] animating the component `T` of a target entity.
+struct ComponentTrack<T> {
+    target: AnimationTarget,
+    /// Last entity the target resolved to, if any.
+    entity: Option<Entity>,
+    tweenable: Box<dyn Tweenable<T> + Send + Sync + 'static>,
+}
+
+/// Track of a [`Timeline`] animating an asset `T`.
+struct AssetTrack<T: Asset> {
+    handle: Handle<T>,
+    tweenable: Box<dyn Tweenable<T> + Send + Sync + 'static>,
+}
+
+/// Type-erased track of a [`Timeline`].
+struct TimelineTrack {
+    /// Timeline time up to which the tweenable of the track was played.
+    played: Duration,
+    /// Type name of the component or asset animated by the track.
+    target_type: &'static str,
+    /// Either a [`ComponentTrack`] or an [`AssetTrack`].
+    track: Box<dyn std::any::Any + Send + Sync + 'static>,
+}
+
+/// Component playing a timeline of animations targeting components and assets of different types.
+///
+/// Unlike [`Sequence`] and [`Tracks`] which animate a single target type, a [`Timeline`] holds
+/// tracks animating any registered component or asset type, on the entity the timeline is
+/// attached to or on other entities designated by an [`AnimationTarget`]. For example a single
+/// timeline can move the [`Transform`] of an entity and fade the color of its [`Sprite`].
+///
+/// All tracks start at the beginning of the timeline, and are played in parallel according to a
+/// single shared clock. That clock can be paused with the [`state`] of the timeline, and moved
+/// to any point in time with [`seek()`]. Delays between tracks are expressed by the tweenable of
+/// each track, for example by starting it with a [`Delay`].
+///
+/// Each track is ticked by the animator system of its target type, so that type must have been
+/// registered with [`TweeningAppExt`], otherwise the track never plays. In debug builds, the
+/// [`TweeningPlugin`] warns about tracks whose target type was not registered. The
+/// [`TweenCompleted`] events of all tracks report the entity the timeline is attached to.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let timeline = Timeline::default()
+///     .with_component_track(
+///         "",
+///         Tween::new(
+///             EaseFunction::QuadraticInOut,
+///             TweeningType::Once,
+///             Duration::from_secs(1),
+///             TransformPositionLens {
+///                 start: Vec3::ZERO,
+///                 end: Vec3::X,
+///             },
+///         ),
+///     )
+///     .with_component_track(
+///         "",
+///         Delay::new(Duration::from_secs(1)).then(Tween::new(
+///             EaseFunction::QuadraticInOut,
+///             TweeningType::Once,
+///             Duration::from_secs(1),
+///             SpriteColorLens {
+///                 start: Color::WHITE,
+///                 end: Color::NONE,
+///             },
+///         )),
+///     );
+/// assert_eq!(timeline.duration(), Duration::from_secs(2));
+/// ```
+///
+/// [`Transform`]: https://docs.rs/bevy/0.7.0/bevy/transform/components/struct.Transform.html
+/// [`Sprite`]: https://docs.rs/bevy/0.7.0/bevy/sprite/struct.Sprite.html
+/// [`state`]: Timeline::state
+/// [`seek()`]: Timeline::seek
+#[derive(Component, Default)]
+pub struct Timeline {
+    /// Control if the clock of this timeline advances or not.
+    pub state: AnimatorState,
+    tracks: Vec<TimelineTrack>,
+    duration: Duration,
+    elapsed: Duration,
+}
+
+impl std::fmt::Debug for Timeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Timeline")
+            .field("state", &self.state)
+            .field(
+                "tracks",
+                &self
+                    .tracks
+                    .iter()
+                    .map(|track| track.target_type)
+                    .collect::<Vec<_>>(),
+            )
+            .field("duration", &self.duration)
+            .field("elapsed", &self.elapsed)
+            .finish()
+    }
+}
+
+impl Timeline {
+    /// Set the initial playback state of the timeline.
+    pub fn with_state(mut self, state: AnimatorState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Add a track animating the component `T` of the given target entity.
+    ///
+    /// The component type `T` must be registered with
+    /// [`TweeningAppExt::register_tweenable_component()`] for the track to play.
+    pub fn with_component_track<T: Component>(
+        mut self,
+        target: impl Into<AnimationTarget>,
+        tween: impl Tweenable<T> + 'static,
+    ) -> Self {
+        self.add_component_track(target, tween);
+        self
+    }
+
+    /// Add a track animating the component `T` of the given target entity.
+    ///
+    /// The target is resolved relative to the entity the timeline is attached to, like for a
+    /// [`HierarchyAnimator`]. The component type `T` must be registered with
+    /// [`TweeningAppExt::register_tweenable_component()`] for the track to play.
+    pub fn add_component_track<T: Component>(
+        &mut self,
+        target: impl Into<AnimationTarget>,
+        tween: impl Tweenable<T> + 'static,
+    ) {
+        self.add_track(
+            tween.duration(),
+            std::any::type_name::<T>(),
+            ComponentTrack {
+                target: target.into(),
+                entity: None,
+                tweenable: Box::new(tween),
+            },
+        );
+    }
+
+    /// Add a track animating the asset `T` with the given handle.
+    ///
+    /// The asset type `T` must be registered with
+    /// [`TweeningAppExt::register_tweenable_asset()`] for the track to play.
+    pub fn with_asset_track<T: Asset>(
+        mut self,
+        handle: Handle<T>,
+        tween: impl Tweenable<T> + 'static,
+    ) -> Self {
+        self.add_asset_track(handle, tween);
+        self
+    }
+
+    /// Add a track animating the asset `T` with the given handle.
+    ///
+    /// The asset type `T` must be registered with
+    /// [`TweeningAppExt::register_tweenable_asset()`] for the track to play.
+    pub fn add_asset_track<T: Asset>(
+        &mut self,
+        handle: Handle<T>,
+        tween: impl Tweenable<T> + 'static,
+    ) {
+        self.add_track(
+            tween.duration(),
+            std::any::type_name::<T>(),
+            AssetTrack {
+                handle,
+                tweenable: Box::new(tween),
+            },
+        );
+    }
+
+    fn add_track(
+        &mut self,
+        duration: Duration,
+        target_type: &'static str,
+        track: impl std::any::Any + Send + Sync + 'static,
+    ) {
+        self.duration = self.duration.max(duration);
+        self.tracks.push(TimelineTrack {
+            played: Duration::ZERO,
+            target_type,
+            track: Box::new(track),
+        });
+    }
+
+    /// Number of tracks in the timeline.
+    pub fn len(&self) -> usize {
+        self.tracks.len()
+    }
+
+    /// Is the timeline without any track?
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+    }
+
+    /// Get the total duration of the timeline, which is the duration of its longest track.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Get the current time of the timeline clock, from zero to the timeline duration.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Move the timeline clock to the given time, clamped to the timeline duration.
+    ///
+    /// The tracks are brought to that time the next time they're ticked, even if the timeline is
+    /// paused. Seeking backward rewinds the tracks then plays them again up to the new time, so
+    /// the [`TweenCompleted`] events of the tweenables completed before that time are sent again.
+    /// Tweenables which didn't start yet at the new time, like a [`Tween`] after a [`Delay`], don't
+    /// write their target, which keeps its current value.
+    pub fn seek(&mut self, time: Duration) {
+        self.elapsed = time.min(self.duration);
+    }
+
+    /// Set the current timeline progress, as a fraction of its duration.
+    ///
+    /// This is equivalent to [`seek()`] with the corresponding time.
+    ///
+    /// [`seek()`]: Timeline::seek
+    pub fn set_progress(&mut self, progress: f32) {
+        self.seek(self.duration.mul_f32(progress.clamp(0., 1.)));
+    }
+
+    /// Get the current progress in \[0:1\] of the timeline.
+    pub fn progress(&self) -> f32 {
+        if self.duration.is_zero() {
+            0.
+        } else {
+            self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        }
+    }
+
+    /// Has the timeline clock reached its end?
+    pub fn is_completed(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Stop the timeline and rewind it.
+    ///
+    /// This changes the timeline state to [`AnimatorState::Paused`] and seeks back to the start.
+    pub fn stop(&mut self) {
+        self.state = AnimatorState::Paused;
+        self.rewind();
+    }
+
+    /// Rewind the timeline to its start.
+    ///
+    /// This does not change the playback state (playing/paused).
+    pub fn rewind(&mut self) {
+        self.seek(Duration::ZERO);
+    }
+}
+
+/// Function cloning an asset into a new one, returning the handle of the new asset.
+type CopyAssetFn<T> = fn(&mut Assets<T>, &Handle<T>) -> Option<Handle<T>>;
+
+/// Component to control the animation of one or more assets.
+///
+/// The animator can animate several assets of the same type with a single tweenable, for
+/// example to fade a batch of materials together. The tweenable is ticked on the first asset
+/// loaded, and its resulting state is written to the other ones with [`Tweenable::apply()`], so
+/// all assets end up in the same animated state.
+///
+/// By default the animated assets are modified in place, so all entities sharing a same asset
+/// are animated together. With [`with_copy_on_animate()`], each asset is instead cloned into a
+/// new unique asset before being animated, and the [`Handle<T>`] component of the entity the
+/// animator is attached to is updated to point to that copy. This allows for example fading a
+/// single sprite without fading all the sprites sharing the same [`ColorMaterial`].
+///
+/// [`with_copy_on_animate()`]: AssetAnimator::with_copy_on_animate
+/// [`Handle<T>`]: https://docs.rs/bevy/0.7.0/bevy/asset/struct.Handle.html
+/// [`ColorMaterial`]: https://docs.rs/bevy/0.7.0/bevy/sprite/struct.ColorMaterial.html
 #[derive(Component)]
 pub struct AssetAnimator<T: Asset> {
     /// Control if this animation is played or not.
     pub state: AnimatorState,
     tweenable: Option<Box<dyn Tweenable<T> + Send + Sync + 'static>>,
-    handle: Handle<T>,
+    handles: Vec<Handle<T>>,
+    /// Function cloning the assets before they're first animated, if copy-on-animate is enabled.
+    copy_asset: Option<CopyAssetFn<T>>,
 }
 
 impl<T: Asset + std::fmt::Debug> std::fmt::Debug for AssetAnimator<T> {
@@ -445,24 +1243,65 @@ impl<T: Asset + std::fmt::Debug> std::fmt::Debug for AssetAnimator<T> {
 
 impl<T: Asset> Default for AssetAnimator<T> {
     fn default() -> Self {
+        record_animator_type::<Self>();
         AssetAnimator {
             state: Default::default(),
             tweenable: None,
-            handle: Default::default(),
+            handles: vec![],
+            copy_asset: None,
         }
     }
 }
 
 impl<T: Asset> AssetAnimator<T> {
     /// Create a new asset animator component from a single tweenable.
-    pub fn new(handle: Handle<T>, tween: impl Tweenable<T> + Send + Sync + 'static) -> Self {
+    pub fn new(handle: Handle<T>, tween: impl Tweenable<T> + 'static) -> Self {
+        Self::from_handles([handle], tween)
+    }
+
+    /// Create a new asset animator component animating several assets with a single tweenable.
+    pub fn from_handles(
+        handles: impl IntoIterator<Item = Handle<T>>,
+        tween: impl Tweenable<T> + 'static,
+    ) -> Self {
         AssetAnimator {
             tweenable: Some(Box::new(tween)),
-            handle,
+            handles: handles.into_iter().collect(),
             ..Default::default()
         }
     }
 
+    /// Clone each animated asset into a new unique asset before animating it.
+    ///
+    /// The copies are made the first time the animator plays. If the entity this animator is
+    /// attached to has a [`Handle<T>`] component referencing one of the animated assets, that
+    /// component is updated to reference the copy instead. Other entities referencing the
+    /// original assets are not animated.
+    ///
+    /// [`Handle<T>`]: https://docs.rs/bevy/0.7.0/bevy/asset/struct.Handle.html
+    pub fn with_copy_on_animate(mut self) -> Self
+    where
+        T: Clone,
+    {
+        self.copy_asset = Some(|assets, handle| {
+            let asset = assets.get(handle)?.clone();
+            Some(assets.add(asset))
+        });
+        self
+    }
+
+    /// Add an asset to animate.
+    pub fn add_handle(&mut self, handle: Handle<T>) {
+        self.handles.push(handle);
+    }
+
+    /// Get the handles of the animated assets.
+    ///
+    /// With copy-on-animate, these are the handles of the copies once the animator started.
+    pub fn handles(&self) -> &[Handle<T>] {
+        &self.handles
+    }
+
     /// Set the initial playback state of the animator.
     pub fn with_state(mut self, state: AnimatorState) -> Self {
         self.state = state;
@@ -470,7 +1309,7 @@ impl<T: Asset> AssetAnimator<T> {
     }
 
     /// Set the top-level tweenable item this animator controls.
-    pub fn set_tweenable(&mut self, tween: impl Tweenable<T> + Send + Sync + 'static) {
+    pub fn set_tweenable(&mut self, tween: impl Tweenable<T> + 'static) {
         self.tweenable = Some(Box::new(tween));
     }
 
@@ -526,6 +1365,26 @@ impl<T: Asset> AssetAnimator<T> {
         }
     }
 
+    /// Set the current elapsed time since the start of the animation.
+    ///
+    /// See [Tweenable::set_elapsed] for details.
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        if let Some(tweenable) = &mut self.tweenable {
+            tweenable.set_elapsed(elapsed)
+        }
+    }
+
+    /// Get the elapsed time since the start of the animation.
+    ///
+    /// See [Tweenable::elapsed] for details.
+    pub fn elapsed(&self) -> Duration {
+        if let Some(tweenable) = &self.tweenable {
+            tweenable.elapsed()
+        } else {
+            Duration::ZERO
+        }
+    }
+
     /// Stop animation playback and rewind the animation.
     ///
     /// This changes the animator state to [`AnimatorState::Paused`] and rewind its tweenable.
@@ -542,9 +1401,179 @@ impl<T: Asset> AssetAnimator<T> {
             tweenable.rewind();
         }
     }
+}
+
+/// Component to control the animation of a resource.
+///
+/// Resources are unique, so this component is generally attached to a dedicated entity, which
+/// the [`TweenCompleted`] events of its tweenable report. Several animators can target the same
+/// resource type, and are all ticked.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// struct CameraShake {
+///     intensity: f32,
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     let tween = Tween::new(
+///         EaseFunction::QuadraticOut,
+///         TweeningType::Once,
+///         Duration::from_millis(500),
+///         lens_fn(1., 0., |shake: &mut CameraShake, intensity| {
+///             shake.intensity = intensity
+///         }),
+///     );
+///     commands.spawn().insert(ResourceAnimator::new(tween));
+/// }
+///
+/// App::default()
+///     .add_plugin(TweeningPlugin::default())
+///     .insert_resource(CameraShake { intensity: 0. })
+///     .register_tweenable_resource::<CameraShake>()
+///     .add_startup_system(setup);
+/// ```
+#[derive(Component)]
+pub struct ResourceAnimator<T: Resource> {
+    /// Control if this animation is played or not.
+    pub state: AnimatorState,
+    tweenable: Option<Box<dyn Tweenable<T> + Send + Sync + 'static>>,
+}
+
+impl<T: Resource + std::fmt::Debug> std::fmt::Debug for ResourceAnimator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResourceAnimator")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<T: Resource> Default for ResourceAnimator<T> {
+    fn default() -> Self {
+        record_animator_type::<Self>();
+        ResourceAnimator {
+            state: Default::default(),
+            tweenable: None,
+        }
+    }
+}
+
+impl<T: Resource> ResourceAnimator<T> {
+    /// Create a new resource animator component from a single tweenable.
+    pub fn new(tween: impl Tweenable<T> + 'static) -> Self {
+        ResourceAnimator {
+            tweenable: Some(Box::new(tween)),
+            ..Default::default()
+        }
+    }
+
+    /// Set the initial playback state of the animator.
+    pub fn with_state(mut self, state: AnimatorState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Set the top-level tweenable item this animator controls.
+    pub fn set_tweenable(&mut self, tween: impl Tweenable<T> + 'static) {
+        self.tweenable = Some(Box::new(tween));
+    }
+
+    /// Get the top-level tweenable this animator is currently controlling.
+    pub fn tweenable(&self) -> Option<&(dyn Tweenable<T> + Send + Sync + 'static)> {
+        if let Some(tweenable) = &self.tweenable {
+            Some(tweenable.as_ref())
+        } else {
+            None
+        }
+    }
+
+    /// Get the top-level mutable tweenable this animator is currently controlling.
+    pub fn tweenable_mut(&mut self) -> Option<&mut (dyn Tweenable<T> + Send + Sync + 'static)> {
+        if let Some(tweenable) = &mut self.tweenable {
+            Some(tweenable.as_mut())
+        } else {
+            None
+        }
+    }
+
+    /// Set the current animation playback progress.
+    ///
+    /// See [`progress()`] for details on the meaning.
+    ///
+    /// [`progress()`]: Animator::progress
+    pub fn set_progress(&mut self, progress: f32) {
+        if let Some(tweenable) = &mut self.tweenable {
+            tweenable.set_progress(progress)
+        }
+    }
+
+    /// Get the current progress of the animation.
+    ///
+    /// See [`Animator::progress()`] for details on the meaning.
+    pub fn progress(&self) -> f32 {
+        if let Some(tweenable) = &self.tweenable {
+            tweenable.progress()
+        } else {
+            0.
+        }
+    }
+
+    /// Set the current elapsed time since the start of the animation.
+    ///
+    /// See [Tweenable::set_elapsed] for details.
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        if let Some(tweenable) = &mut self.tweenable {
+            tweenable.set_elapsed(elapsed)
+        }
+    }
 
-    fn handle(&self) -> Handle<T> {
-        self.handle.clone()
+    /// Get the elapsed time since the start of the animation.
+    ///
+    /// See [Tweenable::elapsed] for details.
+    pub fn elapsed(&self) -> Duration {
+        if let Some(tweenable) = &self.tweenable {
+            tweenable.elapsed()
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Set the animation speed. See [Tweenable::set_speed] for details.
+    pub fn set_speed(&mut self, speed: f32) {
+        if let Some(tweenable) = &mut self.tweenable {
+            tweenable.set_speed(speed)
+        }
+    }
+
+    /// Get the animation speed. See [Tweenable::set_speed] for details.
+    ///
+    /// This is `1` if the animator has no tweenable.
+    pub fn speed(&self) -> f32 {
+        match &self.tweenable {
+            Some(tweenable) => tweenable.speed(),
+            None => 1.,
+        }
+    }
+
+    /// Stop animation playback and rewind the animation.
+    ///
+    /// This changes the animator state to [`AnimatorState::Paused`] and rewind its tweenable.
+    pub fn stop(&mut self) {
+        self.state = AnimatorState::Paused;
+        self.rewind();
+    }
+
+    /// Rewind animation playback to its initial state.
+    ///
+    /// This does not change the playback state (playing/paused).
+    pub fn rewind(&mut self) {
+        if let Some(tweenable) = &mut self.tweenable {
+            tweenable.rewind();
+        }
     }
 }
 
@@ -553,6 +1582,7 @@ mod tests {
     use super::{lens::*, *};
     use bevy::reflect::TypeUuid;
 
+    #[derive(Clone)]
     struct DummyLens {
         start: f32,
         end: f32,
@@ -635,7 +1665,7 @@ mod tests {
         let tween = Tween::new(
             EaseFunction::QuadraticInOut,
             TweeningType::PingPong,
-            std::time::Duration::from_secs(1),
+            Duration::from_secs(1),
             DummyLens { start: 0., end: 1. },
         );
         let animator = Animator::<DummyComponent>::new(tween);
@@ -651,7 +1681,7 @@ mod tests {
             let tween = Tween::<DummyComponent>::new(
                 EaseFunction::QuadraticInOut,
                 TweeningType::PingPong,
-                std::time::Duration::from_secs(1),
+                Duration::from_secs(1),
                 DummyLens { start: 0., end: 1. },
             );
             let animator = Animator::new(tween).with_state(state);
@@ -669,7 +1699,7 @@ mod tests {
         let tween = Tween::<DummyComponent>::new(
             EaseFunction::QuadraticInOut,
             TweeningType::PingPong,
-            std::time::Duration::from_secs(1),
+            Duration::from_secs(1),
             DummyLens { start: 0., end: 1. },
         );
         animator.set_tweenable(tween);
@@ -677,13 +1707,35 @@ mod tests {
         assert!(animator.tweenable_mut().is_some());
     }
 
+    /// Animator::clone()
+    #[test]
+    fn animator_clone() {
+        let tween = Tween::<DummyComponent>::new(
+            EaseFunction::QuadraticInOut,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        );
+        let mut animator = Animator::new(tween).with_state(AnimatorState::Paused);
+        animator.set_progress(0.5);
+
+        let mut clone = animator.clone();
+        assert_eq!(clone.state, AnimatorState::Paused);
+        assert!((clone.progress() - 0.5).abs() < 1e-5);
+        clone.set_progress(1.);
+        assert!((animator.progress() - 0.5).abs() < 1e-5);
+
+        let animator = Animator::<DummyComponent>::default();
+        assert!(animator.clone().tweenable().is_none());
+    }
+
     /// Animator control playback
     #[test]
     fn animator_controls() {
         let tween = Tween::<DummyComponent>::new(
             EaseFunction::QuadraticInOut,
             TweeningType::PingPong,
-            std::time::Duration::from_secs(1),
+            Duration::from_secs(1),
             DummyLens { start: 0., end: 1. },
         );
         let mut animator = Animator::new(tween);
@@ -714,6 +1766,194 @@ mod tests {
         animator.stop();
         assert_eq!(animator.state, AnimatorState::Paused);
         assert!(animator.progress().abs() <= 1e-5);
+
+        animator.set_elapsed(Duration::from_millis(2500));
+        assert_eq!(animator.elapsed(), Duration::from_millis(2500));
+        assert!((animator.progress() - 0.5).abs() <= 1e-5);
+    }
+
+    /// MultiAnimator slot management
+    #[test]
+    fn multi_animator_slots() {
+        let slot = |end: f32| {
+            Animator::new(Tween::<DummyComponent>::new(
+                EaseFunction::QuadraticInOut,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                DummyLens { start: 0., end },
+            ))
+        };
+        let animator = MultiAnimator::default();
+        assert_eq!(animator.state, AnimatorState::Playing);
+        assert!(animator.is_empty());
+        assert!(animator.get("a").is_none());
+
+        let mut animator = animator
+            .with_state(AnimatorState::Paused)
+            .with_slot("a", slot(1.))
+            .with_slot(String::from("b"), slot(2.));
+        assert_eq!(animator.state, AnimatorState::Paused);
+        assert_eq!(animator.len(), 2);
+        assert!(animator.contains("a"));
+        assert!(animator.contains("b"));
+
+        // Replacing a slot keeps its position
+        animator.get_mut("a").unwrap().set_progress(0.5);
+        let previous = animator.insert("a", slot(3.)).unwrap();
+        assert!((previous.progress() - 0.5).abs() <= 1e-5);
+        assert!(animator.get("a").unwrap().progress().abs() <= 1e-5);
+        assert_eq!(
+            animator.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+
+        // Slots are controlled independently
+        animator.get_mut("b").unwrap().stop();
+        assert_eq!(animator.get("a").unwrap().state, AnimatorState::Playing);
+        assert_eq!(animator.get("b").unwrap().state, AnimatorState::Paused);
+
+        assert!(animator.remove("a").is_some());
+        assert!(animator.remove("a").is_none());
+        assert_eq!(animator.len(), 1);
+        animator.clear();
+        assert!(animator.is_empty());
+    }
+
+    /// AnimationTarget conversions
+    #[test]
+    fn animation_target() {
+        let entity = Entity::from_raw(42);
+        assert_eq!(
+            AnimationTarget::from(entity),
+            AnimationTarget::Entity(entity)
+        );
+        assert_eq!(AnimationTarget::from(""), AnimationTarget::Path(vec![]));
+        assert_eq!(
+            AnimationTarget::from("a/b/"),
+            AnimationTarget::Path(vec![Name::new("a"), Name::new("b")])
+        );
+    }
+
+    /// HierarchyAnimator control playback
+    #[test]
+    fn hierarchy_animator_controls() {
+        let tween = |secs| {
+            Tween::<DummyComponent>::new(
+                EaseFunction::QuadraticInOut,
+                TweeningType::Once,
+                Duration::from_secs(secs),
+                DummyLens { start: 0., end: 1. },
+            )
+        };
+        let mut animator = HierarchyAnimator::default();
+        assert_eq!(animator.state, AnimatorState::Playing);
+        assert_eq!(animator.duration(), Duration::ZERO);
+        assert!(animator.progress().abs() <= 1e-5);
+
+        animator.add_track("a", tween(1));
+        let mut animator = animator
+            .with_track(Entity::from_raw(42), tween(2))
+            .with_state(AnimatorState::Paused);
+        assert_eq!(animator.state, AnimatorState::Paused);
+        assert_eq!(animator.duration(), Duration::from_secs(2));
+        assert_eq!(animator.targets().count(), 2);
+
+        animator.set_progress(0.25);
+        assert!((animator.progress() - 0.25).abs() <= 1e-5);
+        assert!((animator.tracks[0].tweenable.progress() - 0.5).abs() <= 1e-5);
+        assert!((animator.tracks[1].tweenable.progress() - 0.25).abs() <= 1e-5);
+
+        animator.state = AnimatorState::Playing;
+        animator.stop();
+        assert_eq!(animator.state, AnimatorState::Paused);
+        assert!(animator.progress().abs() <= 1e-5);
+        assert!(animator.tracks[0].tweenable.progress().abs() <= 1e-5);
+    }
+
+    /// Timeline clock control
+    #[test]
+    fn timeline_controls() {
+        let mut timeline = Timeline::default();
+        assert_eq!(timeline.state, AnimatorState::Playing);
+        assert!(timeline.is_empty());
+        assert_eq!(timeline.duration(), Duration::ZERO);
+        assert!(timeline.progress().abs() <= 1e-5);
+
+        timeline.add_component_track(
+            "",
+            Tween::<DummyComponent>::new(
+                EaseFunction::QuadraticInOut,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                DummyLens { start: 0., end: 1. },
+            ),
+        );
+        let mut timeline = timeline
+            .with_asset_track(
+                Handle::<DummyAsset>::default(),
+                Tween::new(
+                    EaseFunction::QuadraticInOut,
+                    TweeningType::Once,
+                    Duration::from_secs(2),
+                    DummyLens { start: 0., end: 1. },
+                ),
+            )
+            .with_state(AnimatorState::Paused);
+        assert_eq!(timeline.state, AnimatorState::Paused);
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline.duration(), Duration::from_secs(2));
+
+        timeline.seek(Duration::from_secs(1));
+        assert_eq!(timeline.elapsed(), Duration::from_secs(1));
+        assert!((timeline.progress() - 0.5).abs() <= 1e-5);
+        assert!(!timeline.is_completed());
+
+        timeline.seek(Duration::from_secs(5));
+        assert_eq!(timeline.elapsed(), Duration::from_secs(2));
+        assert!(timeline.is_completed());
+
+        timeline.set_progress(0.25);
+        assert_eq!(timeline.elapsed(), Duration::from_millis(500));
+
+        timeline.state = AnimatorState::Playing;
+        timeline.stop();
+        assert_eq!(timeline.state, AnimatorState::Paused);
+        assert_eq!(timeline.elapsed(), Duration::ZERO);
+    }
+
+    /// ResourceAnimator control playback
+    #[test]
+    fn resource_animator_controls() {
+        let mut animator = ResourceAnimator::<DummyAsset>::default();
+        assert_eq!(animator.state, AnimatorState::Playing);
+        assert!(animator.tweenable().is_none());
+        assert!(animator.progress().abs() <= 1e-5);
+
+        let tween = Tween::<DummyAsset>::new(
+            EaseFunction::QuadraticInOut,
+            TweeningType::PingPong,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        );
+        animator.set_tweenable(tween);
+        assert!(animator.tweenable_mut().is_some());
+
+        animator.set_progress(0.5);
+        assert!((animator.progress() - 0.5).abs() <= 1e-5);
+
+        animator.stop();
+        assert_eq!(animator.state, AnimatorState::Paused);
+        assert!(animator.progress().abs() <= 1e-5);
+
+        let tween = Tween::<DummyAsset>::new(
+            EaseFunction::QuadraticInOut,
+            TweeningType::PingPong,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        );
+        let animator = ResourceAnimator::new(tween).with_state(AnimatorState::Paused);
+        assert_eq!(animator.state, AnimatorState::Paused);
+        assert!(animator.tweenable().is_some());
     }
 
     /// AssetAnimator::new()
@@ -722,11 +1962,12 @@ mod tests {
         let tween = Tween::<DummyAsset>::new(
             EaseFunction::QuadraticInOut,
             TweeningType::PingPong,
-            std::time::Duration::from_secs(1),
+            Duration::from_secs(1),
             DummyLens { start: 0., end: 1. },
         );
         let animator = AssetAnimator::new(Handle::<DummyAsset>::default(), tween);
         assert_eq!(animator.state, AnimatorState::default());
+        assert_eq!(animator.handles(), [Handle::default()]);
         let tween = animator.tweenable().unwrap();
         assert_eq!(tween.progress(), 0.);
     }
@@ -738,7 +1979,7 @@ mod tests {
             let tween = Tween::<DummyAsset>::new(
                 EaseFunction::QuadraticInOut,
                 TweeningType::PingPong,
-                std::time::Duration::from_secs(1),
+                Duration::from_secs(1),
                 DummyLens { start: 0., end: 1. },
             );
             let animator =
@@ -753,18 +1994,18 @@ mod tests {
         let mut animator = AssetAnimator::<DummyAsset>::default();
         assert!(animator.tweenable().is_none());
         assert!(animator.tweenable_mut().is_none());
-        assert_eq!(animator.handle(), Handle::<DummyAsset>::default());
+        assert!(animator.handles().is_empty());
 
         let tween = Tween::new(
             EaseFunction::QuadraticInOut,
             TweeningType::PingPong,
-            std::time::Duration::from_secs(1),
+            Duration::from_secs(1),
             DummyLens { start: 0., end: 1. },
         );
         animator.set_tweenable(tween);
         assert!(animator.tweenable().is_some());
         assert!(animator.tweenable_mut().is_some());
-        assert_eq!(animator.handle(), Handle::<DummyAsset>::default());
+        assert!(animator.handles().is_empty());
     }
 
     /// AssetAnimator control playback
@@ -773,7 +2014,7 @@ mod tests {
         let tween = Tween::new(
             EaseFunction::QuadraticInOut,
             TweeningType::PingPong,
-            std::time::Duration::from_secs(1),
+            Duration::from_secs(1),
             DummyLens { start: 0., end: 1. },
         );
         let mut animator = AssetAnimator::new(Handle::<DummyAsset>::default(), tween);
@@ -1,6 +1,29 @@
-use bevy::{asset::Asset, ecs::component::Component, prelude::*};
+use std::{
+    any::TypeId,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
-use crate::{Animator, AnimatorState, AssetAnimator, TweenCompleted};
+use bevy::{
+    asset::Asset,
+    ecs::{
+        component::Component,
+        event::Events,
+        schedule::{
+            IntoSystemDescriptor, ParallelSystemDescriptor, ShouldRun, StageLabel, StateData,
+        },
+        system::Resource,
+    },
+    prelude::*,
+    tasks::ComputeTaskPool,
+    utils::HashSet,
+};
+
+use crate::{
+    AnimationTarget, Animator, AnimatorState, AssetAnimator, AssetTrack, BatchTween,
+    BoxedTweenable, CompletedEvents, ComponentTrack, CopyAssetFn, HierarchyAnimator, Lens,
+    MultiAnimator, ResourceAnimator, Targetable, Timeline, TweenCompleted, Tweenable,
+};
 
 /// Plugin to add systems related to tweening of common components and assets.
 ///
@@ -16,9 +39,39 @@ use crate::{Animator, AnimatorState, AssetAnimator, TweenCompleted};
 /// animating the same component or asset type.
 ///
 /// For other components and assets, including custom ones, the relevant system needs to be
-/// added manually by the application:
-/// - For components, add [`component_animator_system::<T>`] where `T: Component`
-/// - For assets, add [`asset_animator_system::<T>`] where `T: Asset`
+/// registered by the application with the [`TweeningAppExt`] extension trait:
+/// - For components, call [`register_tweenable_component::<T>()`] where `T: Component`
+/// - For assets, call [`register_tweenable_asset::<T>()`] where `T: Asset`
+///
+/// In debug builds, the plugin also emits a warning once per type when an [`Animator`] or
+/// [`AssetAnimator`] is found whose target type was not registered, since that animator
+/// would otherwise silently never play. Animator systems added manually to the app are
+/// recognized once they ran, so they don't trigger that warning.
+///
+/// By default, all animator systems are added to the [`CoreStage::Update`] stage. The plugin
+/// can be configured to add them to another stage with [`with_stage()`], and to order them
+/// relative to other systems of that stage with [`before()`] and [`after()`]. Those settings
+/// also apply to the types registered with [`TweeningAppExt`] after the plugin was added.
+///
+/// ```no_run
+/// # use bevy::{prelude::*, transform::TransformSystem};
+/// # use bevy_tweening::*;
+/// App::default()
+///     .add_plugins(DefaultPlugins)
+///     // Animate before the transform propagation, to avoid a frame of delay
+///     .add_plugin(
+///         TweeningPlugin::default()
+///             .with_stage(CoreStage::PostUpdate)
+///             .before(TransformSystem::TransformPropagate),
+///     )
+///     .run();
+/// ```
+///
+/// The animator systems can also be restricted to run only under some condition, either while
+/// a given [`State`] is active with [`with_state()`], or under a custom run criteria with
+/// [`with_run_criteria()`]. This allows for example freezing all animations while a game is
+/// paused. To instead freeze only some animations, pause their animator with
+/// [`AnimatorState::Paused`].
 ///
 /// This plugin is entirely optional. If you want more control, you can instead add manually
 /// the relevant systems for the exact set of components and assets actually animated.
@@ -28,68 +81,1908 @@ use crate::{Animator, AnimatorState, AssetAnimator, TweenCompleted};
 /// [`Style`]: https://docs.rs/bevy/0.7.0/bevy/ui/struct.Style.html
 /// [`Sprite`]: https://docs.rs/bevy/0.7.0/bevy/sprite/struct.Sprite.html
 /// [`ColorMaterial`]: https://docs.rs/bevy/0.7.0/bevy/sprite/struct.ColorMaterial.html
-#[derive(Debug, Clone, Copy)]
-pub struct TweeningPlugin;
+/// [`register_tweenable_component::<T>()`]: TweeningAppExt::register_tweenable_component
+/// [`register_tweenable_asset::<T>()`]: TweeningAppExt::register_tweenable_asset
+/// [`CoreStage::Update`]: https://docs.rs/bevy/0.7.0/bevy/app/enum.CoreStage.html#variant.Update
+/// [`with_stage()`]: TweeningPlugin::with_stage
+/// [`before()`]: TweeningPlugin::before
+/// [`after()`]: TweeningPlugin::after
+/// [`State`]: https://docs.rs/bevy/0.7.0/bevy/ecs/schedule/struct.State.html
+/// [`with_state()`]: TweeningPlugin::with_state
+/// [`with_run_criteria()`]: TweeningPlugin::with_run_criteria
+#[derive(Clone, Default)]
+pub struct TweeningPlugin {
+    settings: TweeningSettings,
+}
+
+impl std::fmt::Debug for TweeningPlugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TweeningPlugin")
+            .field("stage", &self.settings.stage)
+            .finish()
+    }
+}
+
+impl TweeningPlugin {
+    /// Set the stage the animator systems are added to.
+    ///
+    /// Defaults to [`CoreStage::Update`].
+    ///
+    /// [`CoreStage::Update`]: https://docs.rs/bevy/0.7.0/bevy/app/enum.CoreStage.html#variant.Update
+    pub fn with_stage(mut self, stage: impl StageLabel) -> Self {
+        self.settings.stage = Box::new(stage);
+        self
+    }
+
+    /// Order the animator systems before the system(s) with the given label.
+    ///
+    /// The label must refer to systems of the same stage as the animator systems.
+    pub fn before<L: SystemLabel + Clone>(mut self, label: L) -> Self {
+        self.settings
+            .descriptor_fns
+            .push(Arc::new(move |descriptor| descriptor.before(label.clone())));
+        self
+    }
+
+    /// Order the animator systems after the system(s) with the given label.
+    ///
+    /// The label must refer to systems of the same stage as the animator systems.
+    pub fn after<L: SystemLabel + Clone>(mut self, label: L) -> Self {
+        self.settings
+            .descriptor_fns
+            .push(Arc::new(move |descriptor| descriptor.after(label.clone())));
+        self
+    }
+
+    /// Run the animator systems only when the given run criteria system returns
+    /// [`ShouldRun::Yes`].
+    ///
+    /// While the animator systems don't run, all animations are frozen in their current state,
+    /// as if all animators were [`AnimatorState::Paused`]. Their playback state is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a run criteria was already set, with this method or [`with_state()`]. Bevy
+    /// systems only support a single run criteria, so combine several conditions into a single
+    /// run criteria system instead.
+    ///
+    /// [`ShouldRun::Yes`]: https://docs.rs/bevy/0.7.0/bevy/ecs/schedule/enum.ShouldRun.html#variant.Yes
+    /// [`with_state()`]: TweeningPlugin::with_state
+    pub fn with_run_criteria<S, Param>(self, run_criteria: S) -> Self
+    where
+        S: IntoSystem<(), ShouldRun, Param> + Clone + Send + Sync + 'static,
+        Param: 'static,
+    {
+        self.set_run_criteria(Arc::new(move |descriptor, _| {
+            descriptor.with_run_criteria(run_criteria.clone())
+        }))
+    }
+
+    /// Run the animator systems only while the given [`State`] is active, that is while it's the
+    /// current state on top of the stack of states.
+    ///
+    /// This is a convenience helper for [`with_run_criteria()`]. When the animator systems run in
+    /// [`CoreStage::Update`], where [`App::add_state()`] adds the driver of the state, the run
+    /// criteria is [`State::on_update()`], ordered after that driver. In any other stage, the run
+    /// criteria only compares the current state, without ordering, so the animator systems should
+    /// run in a stage after the one of the driver, like [`CoreStage::PostUpdate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a run criteria was already set, with this method or [`with_run_criteria()`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::*;
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    /// enum GameState {
+    ///     Playing,
+    ///     Paused,
+    /// }
+    ///
+    /// App::default()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_state(GameState::Playing)
+    ///     // Freeze all animations while the game is paused
+    ///     .add_plugin(TweeningPlugin::default().with_state(GameState::Playing))
+    ///     .run();
+    /// ```
+    ///
+    /// [`State`]: https://docs.rs/bevy/0.7.0/bevy/ecs/schedule/struct.State.html
+    /// [`State::on_update()`]: https://docs.rs/bevy/0.7.0/bevy/ecs/schedule/struct.State.html#method.on_update
+    /// [`with_run_criteria()`]: TweeningPlugin::with_run_criteria
+    /// [`CoreStage::Update`]: https://docs.rs/bevy/0.7.0/bevy/app/enum.CoreStage.html#variant.Update
+    /// [`CoreStage::PostUpdate`]: https://docs.rs/bevy/0.7.0/bevy/app/enum.CoreStage.html#variant.PostUpdate
+    /// [`App::add_state()`]: https://docs.rs/bevy/0.7.0/bevy/app/struct.App.html#method.add_state
+    pub fn with_state<S: StateData>(self, state: S) -> Self {
+        self.set_run_criteria(Arc::new(move |descriptor, stage| {
+            let update: &dyn StageLabel = &CoreStage::Update;
+            if stage == update {
+                descriptor.with_run_criteria(State::on_update(state.clone()))
+            } else {
+                // State::on_update() is ordered after the driver of the state, which would log a
+                // warning about an unknown label in any other stage than the one of the driver.
+                let state = state.clone();
+                descriptor.with_run_criteria(move |current: Res<State<S>>| {
+                    if *current.current() == state {
+                        ShouldRun::Yes
+                    } else {
+                        ShouldRun::No
+                    }
+                })
+            }
+        }))
+    }
+
+    fn set_run_criteria(mut self, run_criteria: RunCriteriaFn) -> Self {
+        assert!(
+            self.settings.run_criteria.is_none(),
+            "TweeningPlugin supports a single run criteria, set with either with_run_criteria() \
+            or with_state()"
+        );
+        self.settings.run_criteria = Some(run_criteria);
+        self
+    }
+}
 
 impl Plugin for TweeningPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<TweenCompleted>().add_system(
-            component_animator_system::<Transform>.label(AnimationSystem::AnimationUpdate),
-        );
+        app.insert_resource(self.settings.clone())
+            .register_tweenable_component::<Transform>();
 
         #[cfg(feature = "bevy_ui")]
-        app.add_system(component_animator_system::<Text>.label(AnimationSystem::AnimationUpdate))
-            .add_system(component_animator_system::<Style>.label(AnimationSystem::AnimationUpdate));
+        app.register_tweenable_component::<Text>()
+            .register_tweenable_component::<Style>();
 
         #[cfg(feature = "bevy_sprite")]
-        app.add_system(component_animator_system::<Sprite>.label(AnimationSystem::AnimationUpdate))
-            .add_system(
-                asset_animator_system::<ColorMaterial>.label(AnimationSystem::AnimationUpdate),
+        app.register_tweenable_component::<Sprite>()
+            .register_tweenable_asset::<ColorMaterial>();
+
+        #[cfg(debug_assertions)]
+        add_to_stage(
+            app,
+            &*self.settings.stage,
+            check_animator_registration_system.after(AnimationSystem::AnimationUpdate),
+        );
+    }
+}
+
+/// Extension trait for [`App`] to register the animator systems of custom components and assets.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// #[derive(Component)]
+/// struct MyComponent(f32);
+///
+/// App::default()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugin(TweeningPlugin::default())
+///     .register_tweenable_component::<MyComponent>()
+///     .run();
+/// ```
+///
+/// The systems are added with the stage and ordering settings of the [`TweeningPlugin`], so the
+/// types should be registered after the plugin was added to the app.
+pub trait TweeningAppExt {
+    /// Register the [`component_animator_system::<T>`], [`multi_animator_system::<T>`],
+    /// [`hierarchy_animator_system::<T>`] and [`timeline_component_system::<T>`] for the
+    /// component type `T`, to update each frame the [`Animator<T>`], [`MultiAnimator<T>`] and
+    /// [`HierarchyAnimator<T>`] components, and the [`Timeline`] tracks animating a `T`.
+    ///
+    /// Registering the same type multiple times is valid, and only adds the system once.
+    fn register_tweenable_component<T: Component>(&mut self) -> &mut Self;
+
+    /// Register the [`asset_animator_system::<T>`] and [`timeline_asset_system::<T>`] for the
+    /// asset type `T`, to update each frame the [`AssetAnimator<T>`] components and the
+    /// [`Timeline`] tracks animating a `T`.
+    ///
+    /// Registering the same type multiple times is valid, and only adds the system once.
+    fn register_tweenable_asset<T: Asset>(&mut self) -> &mut Self;
+
+    /// Register the [`resource_animator_system::<T>`] for the resource type `T`, to update each
+    /// frame the [`ResourceAnimator<T>`] components.
+    ///
+    /// Registering the same type multiple times is valid, and only adds the system once.
+    fn register_tweenable_resource<T: Resource>(&mut self) -> &mut Self;
+
+    /// Register the [`static_component_animator_system::<T, U>`] for the component type `T`, to
+    /// update each frame the `Animator<T, U>` components holding a tweenable of type `U`, as
+    /// created by [`Animator::new_static()`].
+    ///
+    /// Registering the same types multiple times is valid, and only adds the system once.
+    fn register_static_animator<T: Component, U: Tweenable<T> + 'static>(&mut self) -> &mut Self;
+
+    /// Register the [`batch_tween_system::<T, L>`] for the component type `T`, to update each
+    /// frame in parallel the [`BatchTween<T, L>`] components animating it through a lens of type
+    /// `L`.
+    ///
+    /// Registering the same types multiple times is valid, and only adds the system once.
+    fn register_batch_tween<T: Component, L: Lens<T> + Copy + Send + Sync + 'static>(
+        &mut self,
+    ) -> &mut Self;
+}
+
+impl TweeningAppExt for App {
+    fn register_tweenable_component<T: Component>(&mut self) -> &mut Self {
+        if register_animator::<Animator<T>>(self) {
+            add_animator_system(
+                self,
+                component_animator_system::<T>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::ComponentAnimationUpdate),
+            );
+        }
+        if register_animator::<MultiAnimator<T>>(self) {
+            add_animator_system(
+                self,
+                multi_animator_system::<T>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::ComponentAnimationUpdate),
+            );
+        }
+        if register_animator::<HierarchyAnimator<T>>(self) {
+            add_animator_system(
+                self,
+                hierarchy_animator_system::<T>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::ComponentAnimationUpdate),
+            );
+        }
+        if register_animator::<ComponentTrack<T>>(self) {
+            register_timeline_clock(self);
+            add_animator_system(
+                self,
+                timeline_component_system::<T>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::ComponentAnimationUpdate)
+                    .after(AnimationSystem::TimelineUpdate),
+            );
+        }
+        self
+    }
+
+    fn register_tweenable_asset<T: Asset>(&mut self) -> &mut Self {
+        if register_animator::<AssetAnimator<T>>(self) {
+            add_animator_system(
+                self,
+                asset_animator_system::<T>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::AssetAnimationUpdate),
+            );
+        }
+        if register_animator::<AssetTrack<T>>(self) {
+            register_timeline_clock(self);
+            add_animator_system(
+                self,
+                timeline_asset_system::<T>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::AssetAnimationUpdate)
+                    .after(AnimationSystem::TimelineUpdate),
+            );
+        }
+        self
+    }
+
+    fn register_tweenable_resource<T: Resource>(&mut self) -> &mut Self {
+        if register_animator::<ResourceAnimator<T>>(self) {
+            add_animator_system(
+                self,
+                resource_animator_system::<T>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::ResourceAnimationUpdate),
+            );
+        }
+        self
+    }
+
+    fn register_static_animator<T: Component, U: Tweenable<T> + 'static>(&mut self) -> &mut Self {
+        if register_animator::<Animator<T, U>>(self) {
+            add_animator_system(
+                self,
+                static_component_animator_system::<T, U>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::ComponentAnimationUpdate),
+            );
+        }
+        self
+    }
+
+    fn register_batch_tween<T: Component, L: Lens<T> + Copy + Send + Sync + 'static>(
+        &mut self,
+    ) -> &mut Self {
+        if register_animator::<BatchTween<T, L>>(self) {
+            add_animator_system(
+                self,
+                batch_tween_system::<T, L>
+                    .label(AnimationSystem::AnimationUpdate)
+                    .label(AnimationSystem::ComponentAnimationUpdate),
+            );
+        }
+        self
+    }
+}
+
+/// Function customizing the descriptor of each animator system, like its ordering.
+type DescriptorFn =
+    Arc<dyn Fn(ParallelSystemDescriptor) -> ParallelSystemDescriptor + Send + Sync + 'static>;
+
+/// Function adding a run criteria to the descriptor of each animator system, given the stage the
+/// system is added to.
+type RunCriteriaFn = Arc<
+    dyn Fn(ParallelSystemDescriptor, &dyn StageLabel) -> ParallelSystemDescriptor
+        + Send
+        + Sync
+        + 'static,
+>;
+
+/// Settings of the [`TweeningPlugin`], applied to all animator systems it adds.
+#[derive(Clone)]
+struct TweeningSettings {
+    stage: Box<dyn StageLabel>,
+    descriptor_fns: Vec<DescriptorFn>,
+    run_criteria: Option<RunCriteriaFn>,
+}
+
+impl Default for TweeningSettings {
+    fn default() -> Self {
+        TweeningSettings {
+            stage: Box::new(CoreStage::Update),
+            descriptor_fns: vec![],
+            run_criteria: None,
+        }
+    }
+}
+
+/// Add an animator system to the app, with the settings of the [`TweeningPlugin`] if any.
+fn add_animator_system(app: &mut App, descriptor: ParallelSystemDescriptor) {
+    let settings = app
+        .world
+        .get_resource::<TweeningSettings>()
+        .cloned()
+        .unwrap_or_default();
+    let mut descriptor = settings
+        .descriptor_fns
+        .iter()
+        .fold(descriptor, |descriptor, descriptor_fn| {
+            descriptor_fn(descriptor)
+        });
+    if let Some(run_criteria) = &settings.run_criteria {
+        descriptor = run_criteria(descriptor, &*settings.stage);
+    }
+    add_to_stage(app, &*settings.stage, descriptor);
+}
+
+/// Add a system to a stage identified by a boxed label.
+fn add_to_stage<Params>(
+    app: &mut App,
+    stage: &dyn StageLabel,
+    system: impl IntoSystemDescriptor<Params>,
+) {
+    app.schedule
+        .get_stage_mut::<SystemStage>(stage)
+        .unwrap_or_else(|| panic!("Stage '{:?}' does not exist or is not a SystemStage", stage))
+        .add_system(system);
+}
+
+/// Set of animator types whose animator system was registered with [`TweeningAppExt`], or
+/// already ran.
+#[derive(Default)]
+struct TweeningRegistry {
+    animators: HashSet<TypeId>,
+    ticked: HashSet<TypeId>,
+}
+
+/// Types of all the animator components created so far, in debug builds.
+///
+/// The animator components don't know about the world they're inserted in, so they're recorded
+/// globally when created, for the registration check to find them in the world by type.
+#[cfg(debug_assertions)]
+static ANIMATOR_TYPES: std::sync::RwLock<Vec<TypeId>> = std::sync::RwLock::new(Vec::new());
+
+/// Record the type `A` of an animator component being created.
+///
+/// This does nothing in release builds, where the registration check is disabled.
+#[inline]
+pub(crate) fn record_animator_type<A: 'static>() {
+    #[cfg(debug_assertions)]
+    {
+        let type_id = TypeId::of::<A>();
+        if !ANIMATOR_TYPES.read().unwrap().contains(&type_id) {
+            let mut animator_types = ANIMATOR_TYPES.write().unwrap();
+            if !animator_types.contains(&type_id) {
+                animator_types.push(type_id);
+            }
+        }
+    }
+}
+
+/// Add the [`timeline_clock_system`], if not already added.
+fn register_timeline_clock(app: &mut App) {
+    if register_animator::<Timeline>(app) {
+        add_animator_system(
+            app,
+            timeline_clock_system.label(AnimationSystem::TimelineUpdate),
+        );
+    }
+}
+
+/// Record the animator type `A` as registered, adding the [`TweenCompleted`] event on first use.
+///
+/// Returns `true` if the type was not already registered.
+fn register_animator<A: 'static>(app: &mut App) -> bool {
+    if !app.world.contains_resource::<Events<TweenCompleted>>() {
+        // Equivalent to App::add_event(), with a label to allow ordering relative to the
+        // double-buffering of the events.
+        app.init_resource::<Events<TweenCompleted>>()
+            .add_system_to_stage(
+                CoreStage::First,
+                Events::<TweenCompleted>::update_system.label(AnimationSystem::EventsUpdate),
+            );
+    }
+    app.world
+        .get_resource_or_insert_with(TweeningRegistry::default)
+        .animators
+        .insert(TypeId::of::<A>())
+}
+
+/// Record that the animator system of the animator type `A` runs, the first time it does.
+///
+/// This lets the registration check recognize the animator systems added manually to the app,
+/// without [`TweeningAppExt`].
+fn mark_ticked<A: 'static>(commands: &mut Commands, marked: &mut bool) {
+    if !*marked {
+        *marked = true;
+        commands.add(|world: &mut World| {
+            world
+                .get_resource_or_insert_with(TweeningRegistry::default)
+                .ticked
+                .insert(TypeId::of::<A>());
+        });
+    }
+}
+
+/// Emit a warning for each animator type present in the world but neither registered nor ticked
+/// by a system added manually.
+///
+/// Since the type of the animated component or asset is not known, this looks for the types of
+/// animator components recorded when they were created. To avoid the cost of scanning all
+/// components each frame, this only scans them when new archetypes are created, which is always
+/// the case when a new animator type is inserted on an entity. The tracks of the [`Timeline`] components are checked when those
+/// change. The warning is emitted on the next frame, to give a chance to the animator systems
+/// added manually, possibly in a later stage, to run once.
+#[cfg(debug_assertions)]
+fn check_animator_registration_system(
+    world: &World,
+    timelines: Query<&Timeline, Changed<Timeline>>,
+    mut archetype_count: Local<usize>,
+    mut pending: Local<bevy::utils::HashMap<TypeId, String>>,
+    mut warned: Local<HashSet<TypeId>>,
+) {
+    let registry = world.get_resource::<TweeningRegistry>();
+    let is_known = |type_id: &TypeId| {
+        matches!(registry, Some(registry)
+            if registry.animators.contains(type_id) || registry.ticked.contains(type_id))
+    };
+    for (type_id, name) in pending.drain() {
+        if !is_known(&type_id) && warned.insert(type_id) {
+            warn!(
+                "Found a {} but its animator system is not registered, so it will never play. \
+                Use App::register_tweenable_component(), App::register_tweenable_asset(), \
+                App::register_tweenable_resource(), App::register_static_animator() or \
+                App::register_batch_tween() to register it.",
+                name
             );
+        }
+    }
+
+    for timeline in timelines.iter() {
+        for track in &timeline.tracks {
+            let type_id = (*track.track).type_id();
+            if !is_known(&type_id) && !warned.contains(&type_id) {
+                pending.insert(
+                    type_id,
+                    format!("Timeline track animating a {}", track.target_type),
+                );
+            }
+        }
+    }
+
+    let archetypes = world.archetypes();
+    if archetypes.len() == *archetype_count {
+        return;
+    }
+    *archetype_count = archetypes.len();
+
+    let components = world.components();
+    let animator_types = ANIMATOR_TYPES.read().unwrap();
+    for archetype in archetypes.iter().filter(|archetype| !archetype.is_empty()) {
+        for component_id in archetype.components() {
+            let info = match components.get_info(component_id) {
+                Some(info) => info,
+                None => continue,
+            };
+            let type_id = match info.type_id() {
+                Some(type_id) => type_id,
+                None => continue,
+            };
+            if animator_types.contains(&type_id)
+                && !is_known(&type_id)
+                && !warned.contains(&type_id)
+            {
+                pending.insert(type_id, info.name().to_string());
+            }
+        }
     }
 }
 
 /// Label enum for the systems relating to animations
-#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, SystemLabel)]
 pub enum AnimationSystem {
-    /// Ticks animations
+    /// Ticks animations. This label is applied to all animator systems.
+    ///
+    /// Those systems run in the stage configured with [`TweeningPlugin::with_stage()`], and only
+    /// when the run criteria configured on the plugin, if any, allow it.
     AnimationUpdate,
+    /// Ticks component animations, via [`component_animator_system`].
+    ComponentAnimationUpdate,
+    /// Ticks asset animations, via [`asset_animator_system`].
+    AssetAnimationUpdate,
+    /// Ticks resource animations, via [`resource_animator_system`].
+    ResourceAnimationUpdate,
+    /// Updates the [`TweenCompleted`] events double buffer, dropping the events of the
+    /// previous frame. This runs in [`CoreStage::First`].
+    ///
+    /// [`CoreStage::First`]: https://docs.rs/bevy/0.7.0/bevy/app/enum.CoreStage.html#variant.First
+    EventsUpdate,
+    /// Advances the clock of all [`Timeline`] components. The animator systems ticking the tracks
+    /// of the timelines run after it.
+    TimelineUpdate,
 }
 
 /// Animator system for components.
 ///
 /// This system extracts all components of type `T` with an `Animator<T>` attached to the same entity,
 /// and tick the animator to animate the component.
+///
+/// The animators are ticked in parallel on the [`ComputeTaskPool`]. Each parallel task collects
+/// the [`TweenCompleted`] events of its animators, which are all sent once the animators are
+/// ticked, in no particular order. Completion callbacks are invoked on the task threads.
+///
+/// Paused animators and animators whose tweenable is idle are skipped without accessing the
+/// component mutably, so it's only marked as changed when actually animated.
+///
+/// [`ComputeTaskPool`]: https://docs.rs/bevy/0.7.0/bevy/tasks/struct.ComputeTaskPool.html
 pub fn component_animator_system<T: Component>(
     time: Res<Time>,
-    mut query: Query<(Entity, &mut T, &mut Animator<T>)>,
-    mut event_writer: EventWriter<TweenCompleted>,
+    task_pool: Res<ComputeTaskPool>,
+    query: Query<(Entity, &mut T, &mut Animator<T>)>,
+    events: ResMut<Events<TweenCompleted>>,
+    commands: Commands,
+    marked: Local<bool>,
+) {
+    static_component_animator_system::<T, BoxedTweenable<T>>(
+        time, task_pool, query, events, commands, marked,
+    );
+}
+
+/// Animator system for components animated by a tweenable of a concrete type.
+///
+/// This system is the equivalent of the [`component_animator_system::<T>`] for the
+/// `Animator<T, U>` components created with [`Animator::new_static()`], which hold their
+/// tweenable of type `U` by value. The tweenable is ticked without any virtual call.
+pub fn static_component_animator_system<T: Component, U: Tweenable<T> + 'static>(
+    time: Res<Time>,
+    task_pool: Res<ComputeTaskPool>,
+    mut query: Query<(Entity, &mut T, &mut Animator<T, U>)>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut commands: Commands,
+    mut marked: Local<bool>,
+) {
+    mark_ticked::<Animator<T, U>>(&mut commands, &mut marked);
+    let delta = time.delta();
+    let completed = Mutex::new(Vec::new());
+    let batch_events = BatchEvents {
+        events: Default::default(),
+        completed: &completed,
+    };
+    // `par_for_each_mut()` clones the closure for each batch, so each task gets its own
+    // `BatchEvents` and never waits on the lock of another one. This is only about performance:
+    // events are flushed when a clone is dropped, and all clones are dropped by the time
+    // `par_for_each_mut()` returns, so none is lost whichever way the closure is shared.
+    query.par_for_each_mut(
+        &task_pool,
+        BATCH_SIZE,
+        move |(entity, mut target, mut animator)| {
+            if !needs_tick(&animator) {
+                return;
+            }
+            if let Some(tweenable) = &mut animator.tweenable {
+                let mut events = batch_events.events.lock().unwrap();
+                tweenable.tick(
+                    delta,
+                    &mut target,
+                    &mut CompletedEvents::new(entity, &mut *events),
+                );
+            }
+        },
+    );
+    events.extend(completed.into_inner().unwrap());
+}
+
+/// Completion events collected by a task of a parallel animator system.
+///
+/// Each task ticks its animators with its own clone, so the lock on [`events`] is never
+/// contended. The events are moved to the shared [`completed`] list once the task is done.
+///
+/// [`events`]: BatchEvents::events
+/// [`completed`]: BatchEvents::completed
+struct BatchEvents<'a> {
+    events: Mutex<Events<TweenCompleted>>,
+    completed: &'a Mutex<Vec<TweenCompleted>>,
+}
+
+impl Clone for BatchEvents<'_> {
+    fn clone(&self) -> Self {
+        BatchEvents {
+            events: Default::default(),
+            completed: self.completed,
+        }
+    }
+}
+
+impl Drop for BatchEvents<'_> {
+    fn drop(&mut self) {
+        let events = self.events.get_mut().unwrap();
+        if !events.is_empty() {
+            self.completed.lock().unwrap().extend(events.drain());
+        }
+    }
+}
+
+/// Batch tween system for components.
+///
+/// This system ticks in parallel on the [`ComputeTaskPool`] all the [`BatchTween<T, L>`]
+/// components, animating the component `T` of their entity. Idle batch tweens are skipped without
+/// accessing the component mutably.
+///
+/// [`ComputeTaskPool`]: https://docs.rs/bevy/0.7.0/bevy/tasks/struct.ComputeTaskPool.html
+pub fn batch_tween_system<T: Component, L: Lens<T> + Copy + Send + Sync + 'static>(
+    time: Res<Time>,
+    task_pool: Res<ComputeTaskPool>,
+    mut query: Query<(&mut T, &mut BatchTween<T, L>)>,
+    mut commands: Commands,
+    mut marked: Local<bool>,
+) {
+    mark_ticked::<BatchTween<T, L>>(&mut commands, &mut marked);
+    let delta = time.delta_seconds();
+    query.par_for_each_mut(&task_pool, BATCH_SIZE, |(mut target, mut tween)| {
+        if !tween.is_idle() {
+            tween.tick(delta, &mut target);
+        }
+    });
+}
+
+/// Number of entities ticked by each task of the parallel animator systems.
+const BATCH_SIZE: usize = 256;
+
+/// Check if an animator is playing a tweenable which isn't idle.
+fn needs_tick<T: Component, U: Tweenable<T> + 'static>(animator: &Animator<T, U>) -> bool {
+    animator.state != AnimatorState::Paused
+        && matches!(&animator.tweenable, Some(tweenable) if !tweenable.is_idle())
+}
+
+/// Animator system for components with multiple animation slots.
+///
+/// This system ticks all playing slots of the `MultiAnimator<T>` components, in slot order. When
+/// a slot writes to the component, the following slots which aren't paused write their current
+/// state again, so that the last slot animating a field wins.
+pub fn multi_animator_system<T: Component>(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut T, &mut MultiAnimator<T>)>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut commands: Commands,
+    mut marked: Local<bool>,
 ) {
+    mark_ticked::<MultiAnimator<T>>(&mut commands, &mut marked);
     for (entity, ref mut target, ref mut animator) in query.iter_mut() {
-        if animator.state != AnimatorState::Paused {
-            if let Some(tweenable) = animator.tweenable_mut() {
-                tweenable.tick(time.delta(), target, entity, &mut event_writer);
+        if animator.state == AnimatorState::Paused
+            || !animator.iter().any(|(_, slot)| needs_tick(slot))
+        {
+            continue;
+        }
+        let mut target = SlotTarget {
+            target,
+            written: false,
+        };
+        for (_, slot) in animator.iter_mut() {
+            let ticked = needs_tick(slot);
+            if slot.state == AnimatorState::Paused {
+                continue;
+            }
+            let tweenable = match slot.tweenable_mut() {
+                Some(tweenable) => tweenable,
+                None => continue,
+            };
+            // Once a slot wrote to the component, the following ones write their state again on
+            // top of it, even if it didn't change, so the last slot wins
+            let overridden = std::mem::take(&mut target.written);
+            if ticked {
+                tweenable.tick(
+                    time.delta(),
+                    &mut target,
+                    &mut CompletedEvents::new(entity, &mut *events),
+                );
+            }
+            if overridden && !target.written {
+                tweenable.apply(target.target_mut());
+            }
+        }
+    }
+}
+
+/// Component animated by the slots of a [`MultiAnimator`], recording if a slot wrote to it.
+struct SlotTarget<'a, 'w, T: Component> {
+    target: &'a mut Mut<'w, T>,
+    written: bool,
+}
+
+impl<T: Component> Targetable<T> for SlotTarget<'_, '_, T> {
+    fn target_mut(&mut self) -> &mut T {
+        self.written = true;
+        self.target.target_mut()
+    }
+}
+
+/// Animator system for components animated from the root of a hierarchy.
+///
+/// This system ticks all `HierarchyAnimator<T>` components, resolving the target entity of each
+/// of their tracks to animate its component `T`.
+#[allow(clippy::too_many_arguments)]
+pub fn hierarchy_animator_system<T: Component>(
+    time: Res<Time>,
+    mut animators: Query<(Entity, &mut HierarchyAnimator<T>)>,
+    mut targets: Query<&mut T>,
+    children: Query<&Children>,
+    names: Query<&Name>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut commands: Commands,
+    mut marked: Local<bool>,
+) {
+    mark_ticked::<HierarchyAnimator<T>>(&mut commands, &mut marked);
+    for (root, mut animator) in animators.iter_mut() {
+        if animator.state == AnimatorState::Paused {
+            continue;
+        }
+        let animator = &mut *animator;
+        let delta = time.delta().mul_f32(animator.speed.abs());
+        animator.elapsed = if animator.speed < 0. {
+            animator.elapsed.saturating_sub(delta)
+        } else {
+            (animator.elapsed + delta).min(animator.duration)
+        };
+        for track in &mut animator.tracks {
+            if track.tweenable.is_idle() {
+                continue;
+            }
+            let entity = resolve_target(
+                root,
+                &track.target,
+                &mut track.entity,
+                &targets,
+                &children,
+                &names,
+            );
+            match entity.and_then(|entity| targets.get_mut(entity).ok()) {
+                Some(mut target) => {
+                    // Catch up with the time elapsed while the target couldn't be resolved
+                    let delta = time.delta() + std::mem::take(&mut track.missed);
+                    track.tweenable.tick(
+                        delta,
+                        &mut target,
+                        &mut CompletedEvents::new(root, &mut *events),
+                    );
+                }
+                None => track.missed += time.delta(),
             }
         }
     }
 }
 
+/// Find the entity designated by an animation target, relative to a root entity.
+///
+/// The `cached` entity the target previously resolved to is reused as long as it still has a
+/// component `T`, and is updated when the target is resolved again.
+fn resolve_target<T: Component>(
+    root: Entity,
+    target: &AnimationTarget,
+    cached: &mut Option<Entity>,
+    targets: &Query<&mut T>,
+    children: &Query<&Children>,
+    names: &Query<&Name>,
+) -> Option<Entity> {
+    if let Some(entity) = *cached {
+        if targets.get(entity).is_ok() {
+            return Some(entity);
+        }
+    }
+    let entity = match target {
+        AnimationTarget::Entity(entity) => Some(*entity),
+        AnimationTarget::Path(path) => path.iter().try_fold(root, |parent, name| {
+            children
+                .get(parent)
+                .ok()?
+                .iter()
+                .copied()
+                .find(|child| matches!(names.get(*child), Ok(child_name) if child_name == name))
+        }),
+    };
+    *cached = entity;
+    entity
+}
+
+/// Advance the clock of all playing [`Timeline`] components.
+pub fn timeline_clock_system(time: Res<Time>, mut query: Query<&mut Timeline>) {
+    for mut timeline in query.iter_mut() {
+        if timeline.state != AnimatorState::Paused && !timeline.is_completed() {
+            let elapsed = timeline.elapsed + time.delta();
+            timeline.seek(elapsed);
+        }
+    }
+}
+
+/// Animator system for the tracks of [`Timeline`] components animating a component.
+///
+/// This system brings all the tracks animating a component `T` to the current time of their
+/// timeline.
+pub fn timeline_component_system<T: Component>(
+    mut timelines: Query<(Entity, &mut Timeline)>,
+    mut targets: Query<&mut T>,
+    children: Query<&Children>,
+    names: Query<&Name>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut commands: Commands,
+    mut marked: Local<bool>,
+) {
+    mark_ticked::<ComponentTrack<T>>(&mut commands, &mut marked);
+    for (root, mut timeline) in timelines.iter_mut() {
+        if !needs_update::<ComponentTrack<T>>(&timeline) {
+            continue;
+        }
+        let timeline = &mut *timeline;
+        for track in &mut timeline.tracks {
+            if track.played == timeline.elapsed {
+                continue;
+            }
+            let component_track = match track.track.downcast_mut::<ComponentTrack<T>>() {
+                Some(component_track) => component_track,
+                None => continue,
+            };
+            let entity = match resolve_target(
+                root,
+                &component_track.target,
+                &mut component_track.entity,
+                &targets,
+                &children,
+                &names,
+            ) {
+                Some(entity) => entity,
+                None => continue,
+            };
+            if let Ok(mut target) = targets.get_mut(entity) {
+                play_track(
+                    &mut track.played,
+                    timeline.elapsed,
+                    component_track.tweenable.as_mut(),
+                    &mut target,
+                    root,
+                    &mut events,
+                );
+            }
+        }
+    }
+}
+
+/// Animator system for the tracks of [`Timeline`] components animating an asset.
+///
+/// This system brings all the tracks animating an asset `T` to the current time of their
+/// timeline.
+pub fn timeline_asset_system<T: Asset>(
+    mut assets: ResMut<Assets<T>>,
+    mut timelines: Query<(Entity, &mut Timeline)>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut commands: Commands,
+    mut marked: Local<bool>,
+) {
+    mark_ticked::<AssetTrack<T>>(&mut commands, &mut marked);
+    for (entity, mut timeline) in timelines.iter_mut() {
+        if !needs_update::<AssetTrack<T>>(&timeline) {
+            continue;
+        }
+        let timeline = &mut *timeline;
+        for track in &mut timeline.tracks {
+            if track.played == timeline.elapsed {
+                continue;
+            }
+            let asset_track = match track.track.downcast_mut::<AssetTrack<T>>() {
+                Some(asset_track) => asset_track,
+                None => continue,
+            };
+            if assets.contains(&asset_track.handle) {
+                play_track(
+                    &mut track.played,
+                    timeline.elapsed,
+                    asset_track.tweenable.as_mut(),
+                    &mut AssetTarget::new(&mut assets, &asset_track.handle),
+                    entity,
+                    &mut events,
+                );
+            }
+        }
+    }
+}
+
+/// Does a timeline have a track of type `Track` lagging behind its clock?
+///
+/// This avoids mutably accessing, and so marking as changed, timelines with nothing to update.
+fn needs_update<Track: 'static>(timeline: &Timeline) -> bool {
+    timeline
+        .tracks
+        .iter()
+        .any(|track| track.played != timeline.elapsed && track.track.is::<Track>())
+}
+
+/// Play a timeline track from the time it was last played to the given time.
+///
+/// When moving backward in time, the track is rewound and played again from the start.
+fn play_track<T>(
+    played: &mut Duration,
+    elapsed: Duration,
+    tweenable: &mut (dyn Tweenable<T> + Send + Sync + 'static),
+    target: &mut dyn Targetable<T>,
+    entity: Entity,
+    events: &mut Events<TweenCompleted>,
+) {
+    if elapsed < *played {
+        tweenable.rewind();
+        *played = Duration::ZERO;
+    }
+    tweenable.tick(
+        elapsed - *played,
+        target,
+        &mut CompletedEvents::new(entity, events),
+    );
+    *played = elapsed;
+}
+
 /// Animator system for assets.
 ///
 /// This system ticks all `AssetAnimator<T>` components to animate their associated asset.
+#[allow(clippy::type_complexity)]
 pub fn asset_animator_system<T: Asset>(
     time: Res<Time>,
     mut assets: ResMut<Assets<T>>,
-    mut query: Query<(Entity, &mut AssetAnimator<T>)>,
-    mut event_writer: EventWriter<TweenCompleted>,
+    mut query: Query<(Entity, &mut AssetAnimator<T>, Option<&mut Handle<T>>)>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut commands: Commands,
+    mut marked: Local<bool>,
 ) {
-    for (entity, ref mut animator) in query.iter_mut() {
-        if animator.state != AnimatorState::Paused {
-            if let Some(target) = assets.get_mut(animator.handle()) {
-                if let Some(tweenable) = animator.tweenable_mut() {
-                    tweenable.tick(time.delta(), target, entity, &mut event_writer);
+    mark_ticked::<AssetAnimator<T>>(&mut commands, &mut marked);
+    for (entity, mut animator, mut entity_handle) in query.iter_mut() {
+        if animator.state == AnimatorState::Paused {
+            continue;
+        }
+        let animator = &mut *animator;
+        if let Some(copy_asset) = animator.copy_asset {
+            // Don't animate the original assets, wait until all of them can be copied
+            if !animator
+                .handles
+                .iter()
+                .all(|handle| assets.contains(handle))
+            {
+                continue;
+            }
+            copy_assets(
+                &mut assets,
+                &mut animator.handles,
+                entity_handle.as_deref_mut(),
+                copy_asset,
+            );
+            animator.copy_asset = None;
+        }
+        let tweenable = match &mut animator.tweenable {
+            Some(tweenable) if !tweenable.is_idle() => tweenable,
+            _ => continue,
+        };
+        // Tick on the first loaded asset, then mirror its state on the other ones if it changed
+        let first = match animator
+            .handles
+            .iter()
+            .position(|handle| assets.contains(handle))
+        {
+            Some(first) => first,
+            None => continue,
+        };
+        let mut target = AssetTarget::new(&mut assets, &animator.handles[first]);
+        tweenable.tick(
+            time.delta(),
+            &mut target,
+            &mut CompletedEvents::new(entity, &mut *events),
+        );
+        if target.written {
+            for handle in &animator.handles[first + 1..] {
+                if let Some(target) = assets.get_mut(handle) {
+                    tweenable.apply(target);
                 }
             }
         }
     }
 }
+
+/// Loaded asset animated by a tweenable, only accessed mutably once the tweenable writes to it.
+///
+/// [`Assets::get_mut()`] sends an [`AssetEvent::Modified`] event, so this avoids reporting as
+/// modified the assets a tweenable is ticked against without changing them.
+struct AssetTarget<'a, T: Asset> {
+    assets: &'a mut Assets<T>,
+    handle: &'a Handle<T>,
+    written: bool,
+}
+
+impl<'a, T: Asset> AssetTarget<'a, T> {
+    /// Create a target for the asset of `handle`, which must be loaded in `assets`.
+    fn new(assets: &'a mut Assets<T>, handle: &'a Handle<T>) -> Self {
+        AssetTarget {
+            assets,
+            handle,
+            written: false,
+        }
+    }
+}
+
+impl<T: Asset> Targetable<T> for AssetTarget<'_, T> {
+    fn target_mut(&mut self) -> &mut T {
+        self.written = true;
+        self.assets.get_mut(self.handle).unwrap()
+    }
+}
+
+/// Replace each asset by a copy made with `copy_asset`, updating the handle of the entity if it
+/// referenced the original asset.
+fn copy_assets<T: Asset>(
+    assets: &mut Assets<T>,
+    handles: &mut [Handle<T>],
+    mut entity_handle: Option<&mut Handle<T>>,
+    copy_asset: CopyAssetFn<T>,
+) {
+    for handle in handles {
+        let copy = match copy_asset(assets, handle) {
+            Some(copy) => copy,
+            None => continue,
+        };
+        if let Some(entity_handle) = entity_handle.as_deref_mut() {
+            if *entity_handle == *handle {
+                *entity_handle = copy.clone();
+            }
+        }
+        *handle = copy;
+    }
+}
+
+/// Animator system for resources.
+///
+/// This system ticks all `ResourceAnimator<T>` components to animate the resource `T`. Nothing
+/// is animated while the resource doesn't exist.
+pub fn resource_animator_system<T: Resource>(
+    time: Res<Time>,
+    mut resource: Option<ResMut<T>>,
+    mut query: Query<(Entity, &mut ResourceAnimator<T>)>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut commands: Commands,
+    mut marked: Local<bool>,
+) {
+    mark_ticked::<ResourceAnimator<T>>(&mut commands, &mut marked);
+    let resource = match &mut resource {
+        Some(resource) => resource,
+        None => return,
+    };
+    for (entity, ref mut animator) in query.iter_mut() {
+        if animator.state == AnimatorState::Paused
+            || !matches!(animator.tweenable(), Some(tweenable) if !tweenable.is_idle())
+        {
+            continue;
+        }
+        if let Some(tweenable) = animator.tweenable_mut() {
+            tweenable.tick(
+                time.delta(),
+                resource,
+                &mut CompletedEvents::new(entity, &mut *events),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{reflect::TypeUuid, tasks::TaskPool};
+
+    use crate::{lens::lens_fn, Delay, EaseFunction, EaseMethod, Tween, TweeningType};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct DummyComponent;
+
+    #[derive(Component)]
+    struct DummyValue(f32);
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    enum DummyState {
+        Running,
+        Frozen,
+    }
+
+    /// Create an app ticking animators of [`DummyValue`] with the given plugin settings, and
+    /// spawn an entity whose value jumps to 1 as soon as its animator is ticked.
+    fn app_with_settings(plugin: TweeningPlugin) -> (App, Entity) {
+        let mut app = App::new();
+        app.insert_resource(Time::default())
+            .insert_resource(plugin.settings)
+            .register_tweenable_component::<DummyValue>();
+        let tween = Tween::new(
+            EaseFunction::QuadraticInOut,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            lens_fn(1., 2., |value: &mut DummyValue, v| value.0 = v),
+        );
+        let entity = app
+            .world
+            .spawn()
+            .insert(DummyValue(0.))
+            .insert(Animator::new(tween))
+            .id();
+        (app, entity)
+    }
+
+    fn value(app: &App, entity: Entity) -> f32 {
+        app.world.get::<DummyValue>(entity).unwrap().0
+    }
+
+    #[derive(Clone, Copy)]
+    struct DummyLens;
+
+    impl Lens<DummyValue> for DummyLens {
+        fn lerp(&mut self, target: &mut DummyValue, ratio: f32) {
+            target.0 = 1. + ratio;
+        }
+    }
+
+    #[test]
+    fn register_tweenable_component() {
+        let mut app = App::new();
+        app.register_tweenable_component::<DummyComponent>();
+        assert!(app.world.contains_resource::<Events<TweenCompleted>>());
+        assert!(register_animator::<Animator<Transform>>(&mut app));
+
+        // Registering again is a no-op
+        assert!(!register_animator::<Animator<DummyComponent>>(&mut app));
+        app.register_tweenable_component::<DummyComponent>();
+        let registry = app.world.get_resource::<TweeningRegistry>().unwrap();
+        assert_eq!(registry.animators.len(), 6);
+        assert!(registry
+            .animators
+            .contains(&TypeId::of::<Animator<DummyComponent>>()));
+        assert!(registry
+            .animators
+            .contains(&TypeId::of::<MultiAnimator<DummyComponent>>()));
+    }
+
+    #[test]
+    fn manual_animator_system() {
+        // Systems added manually are recorded once they ran, and not reported as unregistered
+        let mut app = App::new();
+        app.insert_resource(Time::default())
+            .insert_resource(ComputeTaskPool(TaskPool::new()))
+            .init_resource::<Events<TweenCompleted>>()
+            .add_system(component_animator_system::<DummyValue>)
+            .add_system(timeline_component_system::<DummyValue>);
+        app.update();
+        let registry = app.world.get_resource::<TweeningRegistry>().unwrap();
+        assert!(registry.animators.is_empty());
+        assert!(registry
+            .ticked
+            .contains(&TypeId::of::<Animator<DummyValue>>()));
+        assert!(registry
+            .ticked
+            .contains(&TypeId::of::<ComponentTrack<DummyValue>>()));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn record_animator_types() {
+        // The animator components are found by the registration check from their type
+        #[derive(Component)]
+        struct Recorded;
+        let recorded = |type_id: TypeId| ANIMATOR_TYPES.read().unwrap().contains(&type_id);
+        assert!(!recorded(TypeId::of::<Animator<Recorded>>()));
+        assert!(!recorded(TypeId::of::<MultiAnimator<Recorded>>()));
+
+        let _animator = Animator::<Recorded>::new(Delay::new(Duration::from_secs(1)));
+        let _multi = MultiAnimator::<Recorded>::default();
+        assert!(recorded(TypeId::of::<Animator<Recorded>>()));
+        assert!(recorded(TypeId::of::<MultiAnimator<Recorded>>()));
+        assert!(!recorded(TypeId::of::<HierarchyAnimator<Recorded>>()));
+    }
+
+    #[test]
+    fn component_animator_system_events() {
+        let mut app = App::new();
+        app.insert_resource(Time::default())
+            .register_tweenable_component::<DummyValue>();
+        let entities: Vec<Entity> = (0..1000)
+            .map(|index| {
+                let tween = Tween::new(
+                    EaseMethod::Linear,
+                    TweeningType::Once,
+                    Duration::from_nanos(1),
+                    DummyLens,
+                )
+                .with_completed_event(index % 3 != 0, index);
+                app.world
+                    .spawn()
+                    .insert(DummyValue(0.))
+                    .insert(Animator::new(tween))
+                    .id()
+            })
+            .collect();
+        let mut time = app.world.get_resource_mut::<Time>().unwrap();
+        time.update();
+        std::thread::sleep(Duration::from_millis(1));
+        time.update();
+        app.update();
+
+        // All animators are ticked, and the events of all parallel tasks are sent
+        for entity in &entities {
+            assert!((value(&app, *entity) - 2.).abs() < 1e-5);
+        }
+        let events = app.world.get_resource::<Events<TweenCompleted>>().unwrap();
+        let mut completed: Vec<(Entity, u64)> = events
+            .get_reader()
+            .iter(events)
+            .map(|event| (event.entity, event.user_data))
+            .collect();
+        completed.sort_by_key(|(_, user_data)| *user_data);
+        let expected: Vec<(Entity, u64)> = (0..1000)
+            .filter(|index| index % 3 != 0)
+            .map(|index| (entities[index as usize], index))
+            .collect();
+        assert_eq!(completed, expected);
+    }
+
+    #[test]
+    fn static_component_animator_system() {
+        type DummyTween = Tween<DummyValue, DummyLens>;
+        let mut app = App::new();
+        app.insert_resource(Time::default())
+            .register_static_animator::<DummyValue, DummyTween>();
+        let registry = app.world.get_resource::<TweeningRegistry>().unwrap();
+        assert!(registry
+            .animators
+            .contains(&TypeId::of::<Animator<DummyValue, DummyTween>>()));
+        assert!(!registry
+            .animators
+            .contains(&TypeId::of::<Animator<DummyValue>>()));
+
+        let tween = Tween::new_static(
+            EaseFunction::QuadraticInOut,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            DummyLens,
+        );
+        let entity = app
+            .world
+            .spawn()
+            .insert(DummyValue(0.))
+            .insert(Animator::new_static(tween))
+            .id();
+        app.update();
+        assert!((value(&app, entity) - 1.).abs() < 1e-5);
+
+        // Paused static animators are not ticked either
+        let mut animator = app
+            .world
+            .get_mut::<Animator<DummyValue, DummyTween>>(entity)
+            .unwrap();
+        animator.set_progress(0.5);
+        animator.state = AnimatorState::Paused;
+        app.update();
+        assert!((value(&app, entity) - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn batch_tween_system() {
+        let mut app = App::new();
+        app.insert_resource(Time::default())
+            .insert_resource(ComputeTaskPool(TaskPool::new()))
+            .register_batch_tween::<DummyValue, DummyLens>();
+        let entities: Vec<Entity> = (0..1000)
+            .map(|i| {
+                let mut tween = BatchTween::new(
+                    EaseMethod::Linear,
+                    TweeningType::Once,
+                    Duration::from_secs(1),
+                    DummyLens,
+                );
+                if i % 2 == 1 {
+                    tween.state = AnimatorState::Paused;
+                }
+                app.world.spawn().insert(DummyValue(0.)).insert(tween).id()
+            })
+            .collect();
+        app.update();
+        for (i, entity) in entities.iter().enumerate() {
+            let expected = if i % 2 == 1 { 0. } else { 1. };
+            assert!((value(&app, *entity) - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn plugin_stage() {
+        let mut app = App::new();
+        app.add_plugin(TweeningPlugin::default().with_stage(CoreStage::PostUpdate));
+        let settings = app.world.get_resource::<TweeningSettings>().unwrap();
+        let stage: Box<dyn StageLabel> = Box::new(CoreStage::PostUpdate);
+        assert!(settings.stage.as_ref() == stage.as_ref());
+    }
+
+    #[test]
+    #[should_panic]
+    fn plugin_invalid_stage() {
+        let mut app = App::new();
+        app.add_plugin(TweeningPlugin::default().with_stage("invalid"));
+    }
+
+    #[test]
+    fn plugin_run_criteria() {
+        let (mut app, entity) = app_with_settings(TweeningPlugin::default());
+        app.update();
+        assert!((value(&app, entity) - 1.).abs() < 1e-5);
+
+        let (mut app, entity) =
+            app_with_settings(TweeningPlugin::default().with_run_criteria(|| ShouldRun::No));
+        app.update();
+        assert!(value(&app, entity).abs() < 1e-5);
+    }
+
+    #[test]
+    fn plugin_state() {
+        let (mut app, entity) =
+            app_with_settings(TweeningPlugin::default().with_state(DummyState::Running));
+        app.add_state(DummyState::Frozen);
+        app.update();
+        assert!(value(&app, entity).abs() < 1e-5);
+
+        app.world
+            .get_resource_mut::<State<DummyState>>()
+            .unwrap()
+            .set(DummyState::Running)
+            .unwrap();
+        app.update();
+        app.update();
+        assert!((value(&app, entity) - 1.).abs() < 1e-5);
+
+        // In a later stage than the driver of the state, only the current state is checked
+        let (mut app, entity) = app_with_settings(
+            TweeningPlugin::default()
+                .with_stage(CoreStage::PostUpdate)
+                .with_state(DummyState::Running),
+        );
+        app.add_state(DummyState::Frozen);
+        app.update();
+        assert!(value(&app, entity).abs() < 1e-5);
+
+        app.world
+            .get_resource_mut::<State<DummyState>>()
+            .unwrap()
+            .set(DummyState::Running)
+            .unwrap();
+        app.update();
+        assert!((value(&app, entity) - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn plugin_multiple_run_criteria() {
+        let _ = TweeningPlugin::default()
+            .with_state(DummyState::Running)
+            .with_run_criteria(|| ShouldRun::No);
+    }
+
+    #[test]
+    fn component_animator_change_detection() {
+        let (mut app, entity) = app_with_settings(TweeningPlugin::default());
+        #[derive(Default)]
+        struct ChangedCount(usize);
+        app.init_resource::<ChangedCount>().add_system_to_stage(
+            CoreStage::Last,
+            |query: Query<(), Changed<DummyValue>>, mut count: ResMut<ChangedCount>| {
+                count.0 = query.iter().count();
+            },
+        );
+        let changed = |app: &App| app.world.get_resource::<ChangedCount>().unwrap().0;
+        app.update();
+        assert!((value(&app, entity) - 1.).abs() < 1e-5);
+        assert_eq!(changed(&app), 1);
+
+        // A frozen animation doesn't touch the component
+        let mut animator = app.world.get_mut::<Animator<DummyValue>>(entity).unwrap();
+        animator.set_speed(0.);
+        app.update();
+        assert_eq!(changed(&app), 0);
+
+        // Seeking writes the new state once, then a completed animation doesn't touch it
+        let mut animator = app.world.get_mut::<Animator<DummyValue>>(entity).unwrap();
+        animator.set_speed(1.);
+        animator.set_progress(1.);
+        app.update();
+        assert!((value(&app, entity) - 2.).abs() < 1e-5);
+        assert_eq!(changed(&app), 1);
+        app.update();
+        assert_eq!(changed(&app), 0);
+    }
+
+    #[test]
+    fn running_animator_change_detection() {
+        #[derive(Default)]
+        struct DummyResource(f32);
+        #[derive(Default)]
+        struct ChangedCount(usize, bool);
+
+        let (mut app, entity) = app_with_settings(TweeningPlugin::default());
+        let delayed = || {
+            Delay::new(Duration::from_secs(3600)).then(Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                lens_fn(1., 2., |value: &mut DummyValue, v| value.0 = v),
+            ))
+        };
+        app.world
+            .entity_mut(entity)
+            .insert(Animator::new(delayed()));
+        let multi = app
+            .world
+            .spawn()
+            .insert(DummyValue(0.))
+            .insert(MultiAnimator::default().with_slot("a", Animator::new(delayed())))
+            .id();
+        let tween = Delay::new(Duration::from_secs(3600)).then(Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            lens_fn(1., 2., |value: &mut DummyResource, v| value.0 = v),
+        ));
+        app.world.spawn().insert(ResourceAnimator::new(tween));
+        app.init_resource::<DummyResource>()
+            .register_tweenable_resource::<DummyResource>()
+            .init_resource::<ChangedCount>()
+            .add_system_to_stage(
+                CoreStage::Last,
+                |query: Query<(), Changed<DummyValue>>,
+                 resource: Res<DummyResource>,
+                 mut count: ResMut<ChangedCount>| {
+                    *count = ChangedCount(query.iter().count(), resource.is_changed());
+                },
+            );
+        let changed = |app: &App| {
+            let count = app.world.get_resource::<ChangedCount>().unwrap();
+            (count.0, count.1)
+        };
+        app.update();
+        assert_eq!(changed(&app), (2, true));
+
+        // The running delays don't touch their target
+        let mut time = app.world.get_resource_mut::<Time>().unwrap();
+        time.update();
+        std::thread::sleep(Duration::from_millis(1));
+        time.update();
+        app.update();
+        assert_eq!(changed(&app), (0, false));
+        assert!(value(&app, entity).abs() < 1e-5);
+
+        // Seeking into the tweens writes their new state once, then a running tween whose factor
+        // didn't change doesn't touch its target
+        let progress = 3600.5 / 3601.;
+        app.world
+            .get_mut::<Animator<DummyValue>>(entity)
+            .unwrap()
+            .set_progress(progress);
+        app.world
+            .get_mut::<MultiAnimator<DummyValue>>(multi)
+            .unwrap()
+            .get_mut("a")
+            .unwrap()
+            .set_progress(progress);
+        for mut animator in app
+            .world
+            .query::<&mut ResourceAnimator<DummyResource>>()
+            .iter_mut(&mut app.world)
+        {
+            animator.set_progress(progress);
+        }
+        app.insert_resource(Time::default());
+        app.update();
+        assert_eq!(changed(&app), (2, true));
+        assert!((value(&app, entity) - 1.5).abs() < 1e-3);
+        assert!((value(&app, multi) - 1.5).abs() < 1e-3);
+        app.update();
+        assert_eq!(changed(&app), (0, false));
+    }
+
+    #[test]
+    fn component_animator_sequence_seek() {
+        let (mut app, entity) = app_with_settings(TweeningPlugin::default());
+        let tween = |start: f32, end: f32| {
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                lens_fn(start, end, |value: &mut DummyValue, v| value.0 = v),
+            )
+        };
+        app.world
+            .entity_mut(entity)
+            .insert(Animator::new(tween(0., 1.).then(tween(1., 2.))));
+        app.update();
+        assert!(value(&app, entity).abs() < 1e-5);
+
+        // Seeking to the end writes the end state, even though the sequence is then completed
+        let mut animator = app.world.get_mut::<Animator<DummyValue>>(entity).unwrap();
+        animator.set_progress(1.);
+        app.update();
+        assert!((value(&app, entity) - 2.).abs() < 1e-5);
+
+        // Same when seeking back to the start while playing backward
+        let mut animator = app.world.get_mut::<Animator<DummyValue>>(entity).unwrap();
+        animator.set_speed(-1.);
+        animator.set_progress(0.);
+        app.update();
+        assert!(value(&app, entity).abs() < 1e-5);
+    }
+
+    #[test]
+    fn multi_animator_system() {
+        let (mut app, entity) = app_with_settings(TweeningPlugin::default());
+        let slot = |start: f32, end: f32| {
+            Animator::new(Tween::new(
+                EaseFunction::QuadraticInOut,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                lens_fn(start, end, |value: &mut DummyValue, v| value.0 += v),
+            ))
+        };
+        let mut entity_mut = app.world.entity_mut(entity);
+        entity_mut.remove::<Animator<DummyValue>>();
+        entity_mut.insert(
+            MultiAnimator::default()
+                .with_slot("a", slot(1., 2.))
+                .with_slot("b", slot(10., 20.).with_state(AnimatorState::Paused)),
+        );
+        app.update();
+        assert!((value(&app, entity) - 1.).abs() < 1e-5);
+
+        app.world
+            .get_mut::<MultiAnimator<DummyValue>>(entity)
+            .unwrap()
+            .get_mut("b")
+            .unwrap()
+            .state = AnimatorState::Playing;
+        app.update();
+        // Slot "a" didn't move, so only slot "b" writes to the component
+        assert!((value(&app, entity) - 11.).abs() < 1e-5);
+
+        app.world
+            .get_mut::<MultiAnimator<DummyValue>>(entity)
+            .unwrap()
+            .state = AnimatorState::Paused;
+        app.update();
+        assert!((value(&app, entity) - 11.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn multi_animator_overlapping_slots() {
+        let (mut app, entity) = app_with_settings(TweeningPlugin::default());
+        let slot = |start: f32, end: f32| {
+            Animator::new(Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                lens_fn(start, end, |value: &mut DummyValue, v| value.0 = v),
+            ))
+        };
+        let mut last = slot(10., 20.);
+        last.set_progress(1.);
+        let mut entity_mut = app.world.entity_mut(entity);
+        entity_mut.remove::<Animator<DummyValue>>();
+        entity_mut.insert(
+            MultiAnimator::default()
+                .with_slot("first", slot(1., 2.))
+                .with_slot("last", last),
+        );
+        app.update();
+        assert!((value(&app, entity) - 20.).abs() < 1e-5);
+
+        // The last slot completed, but still overrides the first one when it writes
+        let mut time = app.world.get_resource_mut::<Time>().unwrap();
+        time.update();
+        std::thread::sleep(Duration::from_millis(1));
+        time.update();
+        app.update();
+        assert!((value(&app, entity) - 20.).abs() < 1e-5);
+        assert!(
+            app.world
+                .get::<MultiAnimator<DummyValue>>(entity)
+                .unwrap()
+                .get("first")
+                .unwrap()
+                .progress()
+                > 0.
+        );
+
+        // A paused slot doesn't override the earlier ones
+        app.world
+            .get_mut::<MultiAnimator<DummyValue>>(entity)
+            .unwrap()
+            .get_mut("last")
+            .unwrap()
+            .state = AnimatorState::Paused;
+        app.update();
+        let progress = app
+            .world
+            .get::<MultiAnimator<DummyValue>>(entity)
+            .unwrap()
+            .get("first")
+            .unwrap()
+            .progress();
+        assert!((value(&app, entity) - (1. + progress)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn hierarchy_animator_system() {
+        let (mut app, root) = app_with_settings(TweeningPlugin::default());
+        let tween = |start: f32, end: f32| {
+            Tween::new(
+                EaseFunction::QuadraticInOut,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                lens_fn(start, end, |value: &mut DummyValue, v| value.0 = v),
+            )
+        };
+        let child = app
+            .world
+            .spawn()
+            .insert(Name::new("child"))
+            .insert(DummyValue(0.))
+            .id();
+        let grandchild = app
+            .world
+            .spawn()
+            .insert(Name::new("grandchild"))
+            .insert(DummyValue(0.))
+            .id();
+        let other = app.world.spawn().insert(DummyValue(0.)).id();
+        app.world.entity_mut(root).push_children(&[child]);
+        app.world.entity_mut(child).push_children(&[grandchild]);
+
+        let mut entity_mut = app.world.entity_mut(root);
+        entity_mut.remove::<Animator<DummyValue>>();
+        entity_mut.insert(
+            HierarchyAnimator::default()
+                .with_track("", tween(1., 2.))
+                .with_track("child", tween(3., 4.))
+                .with_track("child/grandchild", tween(5., 6.))
+                .with_track("child/missing", tween(7., 8.))
+                .with_track(other, tween(9., 10.)),
+        );
+        app.update();
+
+        assert!((value(&app, root) - 1.).abs() < 1e-5);
+        assert!((value(&app, child) - 3.).abs() < 1e-5);
+        assert!((value(&app, grandchild) - 5.).abs() < 1e-5);
+        assert!((value(&app, other) - 9.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn hierarchy_animator_late_target() {
+        let (mut app, root) = app_with_settings(TweeningPlugin::default());
+        let tween = || {
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs(10),
+                lens_fn(0., 10., |value: &mut DummyValue, v| value.0 = v),
+            )
+        };
+        let mut entity_mut = app.world.entity_mut(root);
+        entity_mut.remove::<Animator<DummyValue>>();
+        entity_mut.insert(
+            HierarchyAnimator::default()
+                .with_track("", tween())
+                .with_track("child", tween()),
+        );
+        let update = |app: &mut App| {
+            let mut time = app.world.get_resource_mut::<Time>().unwrap();
+            time.update();
+            std::thread::sleep(Duration::from_millis(10));
+            time.update();
+            app.update();
+        };
+        update(&mut app);
+        assert!(value(&app, root) > 0.);
+
+        // The child spawned one frame late catches up with the root
+        let child = app
+            .world
+            .spawn()
+            .insert(Name::new("child"))
+            .insert(DummyValue(0.))
+            .id();
+        app.world.entity_mut(root).push_children(&[child]);
+        update(&mut app);
+        assert!((value(&app, child) - value(&app, root)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn timeline_systems() {
+        #[derive(Component)]
+        struct OtherValue(f32);
+
+        let (mut app, entity) = app_with_settings(TweeningPlugin::default());
+        app.register_tweenable_component::<OtherValue>();
+        let child = app
+            .world
+            .spawn()
+            .insert(Name::new("child"))
+            .insert(OtherValue(0.))
+            .id();
+        app.world.entity_mut(entity).push_children(&[child]);
+        let mut entity_mut = app.world.entity_mut(entity);
+        entity_mut.remove::<Animator<DummyValue>>();
+        entity_mut.insert(
+            Timeline::default()
+                .with_component_track(
+                    "",
+                    Tween::new(
+                        EaseMethod::Linear,
+                        TweeningType::Once,
+                        Duration::from_secs(1),
+                        lens_fn(0., 1., |value: &mut DummyValue, v| value.0 = v),
+                    ),
+                )
+                .with_component_track(
+                    "child",
+                    Delay::new(Duration::from_secs(1)).then(Tween::new(
+                        EaseMethod::Linear,
+                        TweeningType::Once,
+                        Duration::from_secs(1),
+                        lens_fn(0., 10., |value: &mut OtherValue, v| value.0 = v),
+                    )),
+                ),
+        );
+        let other = |app: &App| app.world.get::<OtherValue>(child).unwrap().0;
+        let seek = |app: &mut App, secs: f32| {
+            app.world
+                .get_mut::<Timeline>(entity)
+                .unwrap()
+                .seek(Duration::from_secs_f32(secs));
+            app.update();
+        };
+
+        // Seeking applies even while the timeline doesn't advance
+        seek(&mut app, 0.5);
+        assert!((value(&app, entity) - 0.5).abs() < 1e-5);
+        assert!(other(&app).abs() < 1e-5);
+        seek(&mut app, 1.5);
+        assert!((value(&app, entity) - 1.).abs() < 1e-5);
+        assert!((other(&app) - 5.).abs() < 1e-5);
+        seek(&mut app, 0.25);
+        assert!((value(&app, entity) - 0.25).abs() < 1e-5);
+        // The tween of the child track didn't start yet at that time, so doesn't write anything
+        assert!((other(&app) - 5.).abs() < 1e-5);
+        seek(&mut app, 1.75);
+        assert!((other(&app) - 7.5).abs() < 1e-5);
+
+        // A paused timeline is not marked as changed
+        #[derive(Default)]
+        struct ChangedCount(usize);
+        app.init_resource::<ChangedCount>().add_system_to_stage(
+            CoreStage::Last,
+            |query: Query<(), Changed<Timeline>>, mut count: ResMut<ChangedCount>| {
+                count.0 = query.iter().count();
+            },
+        );
+        app.world.get_mut::<Timeline>(entity).unwrap().state = AnimatorState::Paused;
+        app.update();
+        assert_eq!(app.world.get_resource::<ChangedCount>().unwrap().0, 1);
+        app.update();
+        assert_eq!(app.world.get_resource::<ChangedCount>().unwrap().0, 0);
+    }
+
+    #[test]
+    fn resource_animator_system() {
+        struct DummyResource(f32);
+
+        let (mut app, _) = app_with_settings(TweeningPlugin::default());
+        app.register_tweenable_resource::<DummyResource>();
+        let tween = Tween::new(
+            EaseFunction::QuadraticInOut,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            lens_fn(1., 2., |value: &mut DummyResource, v| value.0 = v),
+        );
+        app.world.spawn().insert(ResourceAnimator::new(tween));
+
+        // Missing resources are skipped
+        app.update();
+
+        app.insert_resource(DummyResource(0.));
+        app.update();
+        let resource = app.world.get_resource::<DummyResource>().unwrap();
+        assert!((resource.0 - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn asset_animator_system() {
+        #[derive(TypeUuid, Clone)]
+        #[uuid = "6ad6cbd1-1ac5-4a4b-8a5e-05f5a1a4c5a6"]
+        struct DummyAsset(f32);
+
+        let (mut app, _) = app_with_settings(TweeningPlugin::default());
+        app.add_plugin(bevy::core::CorePlugin)
+            .add_plugin(bevy::asset::AssetPlugin)
+            .add_asset::<DummyAsset>()
+            .register_tweenable_asset::<DummyAsset>();
+        let tween = || {
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs(3600),
+                lens_fn(1., 2., |value: &mut DummyAsset, v| value.0 = v),
+            )
+        };
+        let mut assets = app.world.get_resource_mut::<Assets<DummyAsset>>().unwrap();
+        let first = assets.add(DummyAsset(0.));
+        let second = assets.add(DummyAsset(0.));
+        let shared = assets.add(DummyAsset(0.));
+        app.world.spawn().insert(AssetAnimator::from_handles(
+            [first.clone(), second.clone()],
+            tween(),
+        ));
+        let entity = app
+            .world
+            .spawn()
+            .insert(shared.clone())
+            .insert(AssetAnimator::new(shared.clone(), tween()).with_copy_on_animate())
+            .id();
+        app.update();
+
+        let assets = app.world.get_resource::<Assets<DummyAsset>>().unwrap();
+        let value = |handle: &Handle<DummyAsset>| assets.get(handle).unwrap().0;
+        assert!((value(&first) - 1.).abs() < 1e-3);
+        assert!((value(&second) - 1.).abs() < 1e-3);
+
+        // The shared asset is left untouched, and the entity now uses an animated copy
+        assert!(value(&shared).abs() < 1e-5);
+        let copy = app.world.get::<Handle<DummyAsset>>(entity).unwrap();
+        assert_ne!(*copy, shared);
+        assert!((value(copy) - 1.).abs() < 1e-3);
+        let animator = app.world.get::<AssetAnimator<DummyAsset>>(entity).unwrap();
+        assert_eq!(animator.handles(), std::slice::from_ref(copy));
+    }
+}
@@ -0,0 +1,349 @@
+//! Helpers to offset in time the animations of a collection of items.
+//!
+//! A [`Stagger`] computes a start delay for each item of a collection, like the buttons of a
+//! menu or the cells of a grid, and builds for each of them a tweenable starting after that
+//! delay. The delays increase with the distance of each item to a starting point, selected
+//! with [`StaggerFrom`], and can be distributed along an easing curve.
+//!
+//! The tweenables can then be played with one [`Animator`] per entity, or from a single
+//! [`HierarchyAnimator`] coordinating all of them.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_tweening::{lens::*, *};
+//! # use std::time::Duration;
+//! // Scale up 4 buttons one after the other, starting from the middle ones
+//! let animator = Stagger::new(Duration::from_millis(250))
+//!     .from(StaggerFrom::Center)
+//!     .hierarchy_animator(["button0", "button1", "button2", "button3"], |_index| {
+//!         Tween::new(
+//!             EaseFunction::BounceOut,
+//!             TweeningType::Once,
+//!             Duration::from_secs(2),
+//!             TransformScaleLens {
+//!                 start: Vec3::splat(0.01),
+//!                 end: Vec3::ONE,
+//!             },
+//!         )
+//!     });
+//! ```
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{AnimationTarget, Animator, Delay, EaseMethod, HierarchyAnimator, Sequence, Tweenable};
+
+/// Starting point of a [`Stagger`], which is the item animated first.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum StaggerFrom {
+    /// Start from the first item, and end with the last one.
+    #[default]
+    First,
+    /// Start from the last item, and end with the first one.
+    Last,
+    /// Start from the center item(s), and end with the items at both ends.
+    ///
+    /// For a grid, this is the center of the grid.
+    Center,
+    /// Start from the item at the given index.
+    Index(usize),
+}
+
+/// Helper to compute increasing start delays for a collection of items.
+///
+/// The delay of each item is proportional to its distance to the starting point of the
+/// stagger, with a delay of [`each`] between two consecutive items. For a grid, the distance is
+/// the euclidean distance between cells, so cells at the same distance from the starting point
+/// start at the same time.
+///
+/// With an easing other than [`EaseMethod::Linear`], the total time span between the first and
+/// the last item is unchanged, but the delays are distributed along the easing curve. For example
+/// [`EaseFunction::QuadraticIn`] starts the first items close together, then spaces the next ones
+/// more and more.
+///
+/// See the [module documentation] for an example.
+///
+/// [`each`]: Stagger::new
+/// [`EaseFunction::QuadraticIn`]: crate::EaseFunction::QuadraticIn
+/// [module documentation]: crate::stagger
+#[derive(Clone, Copy)]
+pub struct Stagger {
+    each: Duration,
+    ease_method: EaseMethod,
+    from: StaggerFrom,
+    reversed: bool,
+    columns: Option<usize>,
+}
+
+impl std::fmt::Debug for Stagger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Stagger")
+            .field("each", &self.each)
+            .field("from", &self.from)
+            .field("reversed", &self.reversed)
+            .field("columns", &self.columns)
+            .finish()
+    }
+}
+
+impl Stagger {
+    /// Create a new stagger with a delay of `each` between two consecutive items.
+    pub fn new(each: Duration) -> Self {
+        Stagger {
+            each,
+            ease_method: EaseMethod::Linear,
+            from: StaggerFrom::First,
+            reversed: false,
+            columns: None,
+        }
+    }
+
+    /// Distribute the delays along an easing curve instead of linearly.
+    pub fn with_ease(mut self, ease_method: impl Into<EaseMethod>) -> Self {
+        self.ease_method = ease_method.into();
+        self
+    }
+
+    /// Set the item the stagger starts from.
+    pub fn from(mut self, from: StaggerFrom) -> Self {
+        self.from = from;
+        self
+    }
+
+    /// Reverse the order of the delays, so the items farthest from the starting point are
+    /// animated first.
+    ///
+    /// For example, reversing a stagger from [`StaggerFrom::Center`] animates the items from
+    /// both ends toward the center.
+    pub fn reversed(mut self) -> Self {
+        self.reversed = !self.reversed;
+        self
+    }
+
+    /// Lay out the items in a grid with the given number of columns, filled row by row.
+    ///
+    /// The last row can be incomplete.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is zero.
+    pub fn with_grid(mut self, columns: usize) -> Self {
+        assert!(columns > 0, "A stagger grid needs at least one column.");
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Compute the start delay of each of `count` items.
+    pub fn delays(&self, count: usize) -> Vec<Duration> {
+        let positions: Vec<Vec2> = (0..count).map(|index| self.position(index)).collect();
+        let origin = match self.from {
+            StaggerFrom::First => Vec2::ZERO,
+            StaggerFrom::Last => self.position(count.saturating_sub(1)),
+            StaggerFrom::Center => {
+                let (min, max) = positions
+                    .iter()
+                    .fold((Vec2::ZERO, Vec2::ZERO), |(min, max), pos| {
+                        (min.min(*pos), max.max(*pos))
+                    });
+                (min + max) / 2.
+            }
+            StaggerFrom::Index(index) => self.position(index),
+        };
+        let distances: Vec<f32> = positions.iter().map(|pos| pos.distance(origin)).collect();
+        let max_distance = distances.iter().copied().fold(0., f32::max);
+        if max_distance <= 0. {
+            return vec![Duration::ZERO; count];
+        }
+        distances
+            .iter()
+            .map(|distance| {
+                let mut ratio = distance / max_distance;
+                if self.reversed {
+                    ratio = 1. - ratio;
+                }
+                self.each
+                    .mul_f32(max_distance * self.ease_method.sample(ratio).max(0.))
+            })
+            .collect()
+    }
+
+    /// Build a tweenable for each of `count` items, delayed according to the stagger.
+    ///
+    /// The `factory` is invoked with the index of each item to create its tweenable, which is
+    /// then started after the delay of that item.
+    pub fn tweenables<T: 'static, U>(
+        &self,
+        count: usize,
+        mut factory: impl FnMut(usize) -> U,
+    ) -> Vec<Sequence<T>>
+    where
+        U: Tweenable<T> + Send + Sync + 'static,
+    {
+        self.delays(count)
+            .into_iter()
+            .enumerate()
+            .map(|(index, delay)| Delay::new(delay).then(factory(index)))
+            .collect()
+    }
+
+    /// Build an [`Animator`] for each of `count` items, delayed according to the stagger.
+    ///
+    /// See [`tweenables()`] for details.
+    ///
+    /// [`tweenables()`]: Stagger::tweenables
+    pub fn animators<T, U>(&self, count: usize, factory: impl FnMut(usize) -> U) -> Vec<Animator<T>>
+    where
+        T: Component,
+        U: Tweenable<T> + Send + Sync + 'static,
+    {
+        self.tweenables(count, factory)
+            .into_iter()
+            .map(Animator::new)
+            .collect()
+    }
+
+    /// Insert on each entity an [`Animator`] delayed according to the stagger.
+    ///
+    /// The `factory` is invoked with the index of each entity in `entities` and the entity itself
+    /// to create its tweenable.
+    pub fn insert_animators<T, U>(
+        &self,
+        commands: &mut Commands,
+        entities: &[Entity],
+        mut factory: impl FnMut(usize, Entity) -> U,
+    ) where
+        T: Component,
+        U: Tweenable<T> + Send + Sync + 'static,
+    {
+        let animators = self.animators(entities.len(), |index| factory(index, entities[index]));
+        for (entity, animator) in entities.iter().zip(animators) {
+            commands.entity(*entity).insert(animator);
+        }
+    }
+
+    /// Build a single [`HierarchyAnimator`] with one track per target, each delayed according to
+    /// the stagger.
+    ///
+    /// The `factory` is invoked with the index of each target to create its tweenable.
+    pub fn hierarchy_animator<T, U, A>(
+        &self,
+        targets: impl IntoIterator<Item = A>,
+        factory: impl FnMut(usize) -> U,
+    ) -> HierarchyAnimator<T>
+    where
+        T: Component,
+        U: Tweenable<T> + Send + Sync + 'static,
+        A: Into<AnimationTarget>,
+    {
+        let targets: Vec<AnimationTarget> = targets.into_iter().map(Into::into).collect();
+        let tweenables = self.tweenables(targets.len(), factory);
+        targets.into_iter().zip(tweenables).fold(
+            HierarchyAnimator::default(),
+            |animator, (target, tweenable)| animator.with_track(target, tweenable),
+        )
+    }
+
+    /// Position of an item, in units of items.
+    fn position(&self, index: usize) -> Vec2 {
+        match self.columns {
+            Some(columns) => Vec2::new((index % columns) as f32, (index / columns) as f32),
+            None => Vec2::new(index as f32, 0.),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lens::*, EaseFunction, Tween, TweeningType};
+
+    use super::*;
+
+    /// Round durations to milliseconds, ignoring floating-point errors.
+    fn millis(delays: &[Duration]) -> Vec<u32> {
+        delays
+            .iter()
+            .map(|delay| (delay.as_secs_f32() * 1000.).round() as u32)
+            .collect()
+    }
+
+    #[test]
+    fn stagger_from() {
+        let stagger = Stagger::new(Duration::from_millis(100));
+        assert_eq!(millis(&stagger.delays(4)), [0, 100, 200, 300]);
+        assert_eq!(
+            millis(&stagger.from(StaggerFrom::Last).delays(4)),
+            [300, 200, 100, 0]
+        );
+        assert_eq!(
+            millis(&stagger.from(StaggerFrom::Center).delays(5)),
+            [200, 100, 0, 100, 200]
+        );
+        assert_eq!(
+            millis(&stagger.from(StaggerFrom::Center).delays(4)),
+            [150, 50, 50, 150]
+        );
+        assert_eq!(
+            millis(&stagger.from(StaggerFrom::Index(1)).delays(4)),
+            [100, 0, 100, 200]
+        );
+        assert_eq!(
+            millis(&stagger.from(StaggerFrom::Center).reversed().delays(5)),
+            [0, 100, 200, 100, 0]
+        );
+        assert!(stagger.delays(0).is_empty());
+        assert_eq!(millis(&stagger.delays(1)), [0]);
+    }
+
+    #[test]
+    fn stagger_ease() {
+        let stagger = Stagger::new(Duration::from_millis(100)).with_ease(EaseFunction::QuadraticIn);
+        assert_eq!(millis(&stagger.delays(3)), [0, 50, 200]);
+    }
+
+    #[test]
+    fn stagger_grid() {
+        let stagger = Stagger::new(Duration::from_millis(100)).with_grid(2);
+        assert_eq!(millis(&stagger.delays(4)), [0, 100, 100, 141]);
+        assert_eq!(
+            millis(&stagger.from(StaggerFrom::Center).delays(4)),
+            [71, 71, 71, 71]
+        );
+        // Incomplete last row
+        assert_eq!(
+            millis(&stagger.from(StaggerFrom::Last).delays(3)),
+            [100, 141, 0]
+        );
+    }
+
+    #[test]
+    fn stagger_tweenables() {
+        let stagger = Stagger::new(Duration::from_millis(100));
+        let factory = |_| {
+            Tween::<Transform>::new(
+                EaseFunction::QuadraticInOut,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                TransformScaleLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+        let durations: Vec<_> = stagger
+            .tweenables(3, factory)
+            .iter()
+            .map(|seq| seq.duration())
+            .collect();
+        assert_eq!(millis(&durations), [1000, 1100, 1200]);
+
+        let animators = stagger.animators(3, factory);
+        assert_eq!(animators.len(), 3);
+
+        let animator = stagger.hierarchy_animator(["a", "b"], factory);
+        assert_eq!(millis(&[animator.duration()]), [1100]);
+        assert_eq!(animator.targets().count(), 2);
+    }
+}
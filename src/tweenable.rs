@@ -1,7 +1,11 @@
 use std::cmp::min;
+use std::sync::Arc;
 use std::time::Duration;
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::{event::Events, system::Resource},
+    prelude::*,
+};
 
 use crate::{EaseMethod, Lens, TweeningDirection, TweeningType};
 
@@ -18,6 +22,29 @@ pub enum TweenState {
     Completed,
 }
 
+/// Error returned by the fallible constructors of the tweenables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TweeningError {
+    /// A looping tween was created with a zero duration. See [`Tween::try_new()`].
+    ZeroDurationLoop,
+    /// A sequence was created without any tweenable. See [`Sequence::try_new()`].
+    EmptySequence,
+    /// Tracks were created without any tweenable. See [`Tracks::try_new()`].
+    EmptyTracks,
+}
+
+impl std::fmt::Display for TweeningError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TweeningError::ZeroDurationLoop => write!(f, "looping tween with a zero duration"),
+            TweeningError::EmptySequence => write!(f, "sequence without any tweenable"),
+            TweeningError::EmptyTracks => write!(f, "tracks without any tweenable"),
+        }
+    }
+}
+
+impl std::error::Error for TweeningError {}
+
 /// Event raised when a tween completed.
 ///
 /// This event is raised when a tween completed. For non-looping tweens, this is raised once at the
@@ -44,11 +71,175 @@ pub struct TweenCompleted {
     pub user_data: u64,
 }
 
+/// Receiver of the completions of the tweens ticked by [`Tweenable::tick()`].
+///
+/// This allows ticking a tweenable outside of the ECS, for example in a plain function or a unit
+/// test, without a Bevy [`World`] to send events to. The animator systems tick their tweenables
+/// with a [`CompletedEvents`] sink, which sends a [`TweenCompleted`] event for each completion.
+///
+/// The sink is implemented for `()`, which ignores all completions, and for `Vec<u64>`, which
+/// collects the user data of the completed tweens.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let mut tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     TweeningType::Once,
+///     Duration::from_secs(1),
+///     TransformPositionLens {
+///         start: Vec3::ZERO,
+///         end: Vec3::ONE,
+///     },
+/// )
+/// .with_completed_event(true, 42);
+///
+/// let mut transform = Transform::default();
+/// let mut completed = vec![];
+/// tween.tick(Duration::from_secs(2), &mut transform, &mut completed);
+/// assert_eq!(completed, vec![42]);
+/// assert_eq!(transform.translation, Vec3::ONE);
+/// ```
+///
+/// [`World`]: bevy::ecs::world::World
+pub trait CompletionSink {
+    /// Report the completion of an iteration of a tween whose completion events are enabled,
+    /// with the user data set by [`Tween::with_completed_event()`].
+    fn completed(&mut self, user_data: u64);
+
+    /// The entity passed to the completion callbacks of the tweens.
+    ///
+    /// Defaults to the placeholder [`Entity::from_raw(u32::MAX)`], for tweenables ticked outside
+    /// of the ECS. **This placeholder is not a valid entity**: it doesn't refer to the animated
+    /// entity, and may even alias an unrelated live entity, so it must never be used with
+    /// [`Commands`] or queries. Sinks used to tick tweenables animating an entity must override
+    /// this method to return that entity, like [`CompletedEvents`] does.
+    ///
+    /// [`Entity::from_raw(u32::MAX)`]: Entity::from_raw
+    /// [`Commands`]: bevy::ecs::system::Commands
+    fn entity(&self) -> Entity {
+        Entity::from_raw(u32::MAX)
+    }
+}
+
+impl CompletionSink for () {
+    fn completed(&mut self, _user_data: u64) {}
+}
+
+impl CompletionSink for Vec<u64> {
+    fn completed(&mut self, user_data: u64) {
+        self.push(user_data);
+    }
+}
+
+/// Completion sink sending a [`TweenCompleted`] event for each completion of the tweens animating
+/// an entity.
+///
+/// The events are sent either directly to the [`Events<TweenCompleted>`] resource, or through an
+/// [`EventWriter<TweenCompleted>`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// #[derive(Component)]
+/// struct MyAnimation(Tween<Transform>);
+///
+/// fn my_system(
+///     time: Res<Time>,
+///     mut query: Query<(Entity, &mut Transform, &mut MyAnimation)>,
+///     mut writer: EventWriter<TweenCompleted>,
+/// ) {
+///     for (entity, mut transform, mut animation) in query.iter_mut() {
+///         let mut events = CompletedEvents::new(entity, &mut writer);
+///         animation.0.tick(time.delta(), &mut transform, &mut events);
+///     }
+/// }
+/// ```
+///
+/// [`Events<TweenCompleted>`]: bevy::ecs::event::Events
+/// [`EventWriter<TweenCompleted>`]: bevy::ecs::event::EventWriter
+pub struct CompletedEvents<'a, E> {
+    entity: Entity,
+    events: &'a mut E,
+}
+
+impl<'a, E> CompletedEvents<'a, E> {
+    /// Create a sink sending the completion events of the tweens animating `entity` to `events`.
+    pub fn new(entity: Entity, events: &'a mut E) -> Self {
+        CompletedEvents { entity, events }
+    }
+}
+
+impl CompletionSink for CompletedEvents<'_, Events<TweenCompleted>> {
+    fn completed(&mut self, user_data: u64) {
+        self.events.send(TweenCompleted {
+            entity: self.entity,
+            user_data,
+        });
+    }
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+impl CompletionSink for CompletedEvents<'_, EventWriter<'_, '_, TweenCompleted>> {
+    fn completed(&mut self, user_data: u64) {
+        self.events.send(TweenCompleted {
+            entity: self.entity,
+            user_data,
+        });
+    }
+
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+/// Target of the tweenables, dereferenced mutably only when a tweenable writes to it.
+///
+/// [`Tweenable::tick()`] takes its target as a [`Targetable`], and only calls [`target_mut()`]
+/// when it actually writes a new state. This lets the animator systems tick a [`Mut`] or
+/// [`ResMut`] without marking the component or resource as changed while the animation doesn't
+/// modify it, for example during a [`Delay`] or when the interpolation factor didn't change since
+/// the previous tick.
+///
+/// Any value is its own target, so a plain `&mut T` can be ticked as is.
+///
+/// [`target_mut()`]: Targetable::target_mut
+pub trait Targetable<T> {
+    /// Dereference the target mutably, marking it as changed for Bevy's change detection.
+    fn target_mut(&mut self) -> &mut T;
+}
+
+impl<T> Targetable<T> for T {
+    fn target_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T> Targetable<T> for Mut<'_, T> {
+    fn target_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: Resource> Targetable<T> for ResMut<'_, T> {
+    fn target_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 struct AnimClock {
     elapsed: Duration,
     duration: Duration,
-    original: Duration,
     is_looping: bool,
 }
 
@@ -57,7 +248,6 @@ impl AnimClock {
         AnimClock {
             elapsed: Duration::ZERO,
             duration,
-            original: duration,
             is_looping,
         }
     }
@@ -67,6 +257,14 @@ impl AnimClock {
 
         if self.elapsed < self.duration {
             0
+        } else if self.is_looping && self.duration.is_zero() {
+            // Zero-length loops complete a single iteration per tick, instead of infinitely many
+            self.elapsed = Duration::ZERO;
+            if duration.is_zero() {
+                0
+            } else {
+                1
+            }
         } else if self.is_looping {
             let elapsed = self.elapsed.as_secs_f32();
             let duration = self.duration.as_secs_f32();
@@ -90,7 +288,7 @@ impl AnimClock {
     }
 
     fn progress(&self) -> f32 {
-        self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        progress(self.elapsed, self.duration)
     }
 
     fn completed(&self) -> bool {
@@ -102,8 +300,21 @@ impl AnimClock {
     }
 }
 
+/// Ratio of `elapsed` over `duration`. Zero-length animations jump straight to their end, so
+/// their progress is always `1.0`.
+fn progress(elapsed: Duration, duration: Duration) -> f32 {
+    if duration.is_zero() {
+        1.
+    } else {
+        elapsed.as_secs_f32() / duration.as_secs_f32()
+    }
+}
+
 /// An animatable entity, either a single [`Tween`] or a collection of them.
-pub trait Tweenable<T>: Send + Sync {
+///
+/// Tweenables are [`Clone`], so the same animation can be built once then played on several
+/// entities. Type-erased tweenables are cloned with [`CloneTweenable::box_clone()`].
+pub trait Tweenable<T>: CloneTweenable<T> + Send + Sync {
     /// Get the total duration of the animation.
     ///
     /// For non-looping tweenables ([`TweeningType::Once`]), this is the total animation duration.
@@ -115,13 +326,27 @@ pub trait Tweenable<T>: Send + Sync {
     /// reach back the same state in this case is the double of the returned value.
     fn duration(&self) -> Duration;
 
-    /// Set the animation speed. Defaults to 1.
+    /// Set the animation speed, as a playback rate. Defaults to 1.
+    ///
+    /// The speed multiplies the delta time the tweenable is ticked with. A speed of 2 plays the
+    /// animation twice as fast, while a speed of 0.5 plays it at half speed. A speed of 0 freezes
+    /// the animation in its current state. Negative speeds play the animation backward in time,
+    /// toward its start, which is reported as [`TweenState::Completed`] once reached. Completion
+    /// events and callbacks are only raised when playing forward.
+    ///
+    /// The speed doesn't change the [`duration()`] of the tweenable. For a [`Sequence`] or
+    /// [`Tracks`], it applies on top of the speed of each child tweenable.
     ///
-    /// Speeds greater than 1 slow down time. That is, a speed of 10 means the animation will
-    /// take 10 times longer to complete whereas a speed of 0.5 means the animation will complete
-    /// twice as fast.
+    /// [`duration()`]: Tweenable::duration
     fn set_speed(&mut self, speed: f32);
 
+    /// Get the animation speed, as a playback rate.
+    ///
+    /// See [`set_speed()`] for details.
+    ///
+    /// [`set_speed()`]: Tweenable::set_speed
+    fn speed(&self) -> f32;
+
     /// Return `true` if the animation is looping.
     ///
     /// Looping tweenables are of type [`TweeningType::Loop`] or [`TweeningType::PingPong`].
@@ -146,6 +371,39 @@ pub trait Tweenable<T>: Send + Sync {
     ///   loops over to `0.0` immediately when it changes direction at either endpoint.
     fn progress(&self) -> f32;
 
+    /// Set the current elapsed time since the start of the animation.
+    ///
+    /// Unlike [`set_progress()`], this seeks by absolute time across all the iterations of a
+    /// looping tweenable. The number of times the tweenable completed and, for
+    /// [`TweeningType::PingPong`], its current direction are updated as if it had been ticked
+    /// from its start for that time. For non-looping tweenables, the elapsed time is clamped to
+    /// the [`duration()`].
+    ///
+    /// Like [`set_progress()`], this doesn't update the target nor raise any completion event.
+    ///
+    /// The default implementation only seeks within a single iteration, through
+    /// [`set_progress()`], and ignores the completed iterations of looping tweenables. All the
+    /// tweenables of this crate override it with an exact implementation.
+    ///
+    /// [`set_progress()`]: Tweenable::set_progress
+    /// [`duration()`]: Tweenable::duration
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.set_progress(progress(elapsed, self.duration()).min(1.));
+    }
+
+    /// Get the elapsed time since the start of the animation.
+    ///
+    /// For looping tweenables, this includes the time of all the iterations completed so far.
+    ///
+    /// The default implementation derives the elapsed time from [`progress()`], so only
+    /// accounts for the current iteration of looping tweenables. All the tweenables of this
+    /// crate override it with an exact implementation.
+    ///
+    /// [`progress()`]: Tweenable::progress
+    fn elapsed(&self) -> Duration {
+        self.duration().mul_f32(self.progress())
+    }
+
     /// Tick the animation, advancing it by the given delta time and mutating the given target component or asset.
     ///
     /// This returns [`TweenState::Active`] if the tweenable didn't reach its final state yet (progress < `1.0`),
@@ -156,16 +414,61 @@ pub trait Tweenable<T>: Send + Sync {
     /// state of the tweenable without actually modifying the tweenable state. This is useful after certain operations
     /// like [`rewind()`] or [`set_progress()`] whose effect is otherwise only visible on target on next frame.
     ///
+    /// The target is only dereferenced mutably through [`Targetable::target_mut()`] when the tweenable
+    /// writes to it, so a [`Mut`] or [`ResMut`] target isn't marked as changed by a tick without effect.
+    ///
+    /// The completions of the tweens with completion events enabled are reported to `completions`, which
+    /// also provides the entity passed to their completion callbacks. See [`CompletionSink`] for details.
+    ///
     /// [`rewind()`]: Tweenable::rewind
     /// [`set_progress()`]: Tweenable::set_progress
     fn tick(
         &mut self,
         delta: Duration,
-        target: &mut T,
-        entity: Entity,
-        event_writer: &mut EventWriter<TweenCompleted>,
+        target: &mut dyn Targetable<T>,
+        completions: &mut dyn CompletionSink,
     ) -> TweenState;
 
+    /// Write the current state of the animation to the given target, without advancing it.
+    ///
+    /// Unlike [`tick()`] with a [`Duration::ZERO`] delta, this also writes the final state of completed
+    /// tweenables, and never sends any completion event nor invokes any callback. This allows mirroring on
+    /// several targets the state of a tweenable ticked on a single one, like [`AssetAnimator`] does when
+    /// animating several assets.
+    ///
+    /// The default implementation does nothing. Custom tweenables should override it to support
+    /// animators with several targets.
+    ///
+    /// [`tick()`]: Tweenable::tick
+    /// [`AssetAnimator`]: crate::AssetAnimator
+    fn apply(&mut self, _target: &mut T) {}
+
+    /// Write to the given target the state of the animation at the given elapsed time since its start.
+    ///
+    /// This evaluates the tweenable as if it had been ticked from its start for `elapsed`, like
+    /// [`set_elapsed()`] followed by [`apply()`] would, but without mutating the tweenable. Its clock,
+    /// number of completions and direction are left untouched, and no completion event is sent nor
+    /// any callback invoked. This allows previewing an animation, for example to scrub through it in an
+    /// editor, or evaluating it at several times in a row.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// [`set_elapsed()`]: Tweenable::set_elapsed
+    /// [`apply()`]: Tweenable::apply
+    fn sample_at(&self, _elapsed: Duration, _target: &mut T) {}
+
+    /// Return `true` if ticking the tweenable would have no effect, neither on its own state nor
+    /// on its target.
+    ///
+    /// This is the case once a tweenable completed and wrote its final state to its target, or
+    /// while it's frozen with a speed of `0`. The animator systems don't tick idle tweenables, so
+    /// they don't mark their target as changed and preserve Bevy's change detection.
+    ///
+    /// The default implementation returns `false`, so custom tweenables are always ticked.
+    fn is_idle(&self) -> bool {
+        false
+    }
+
     /// Get the number of times this tweenable completed.
     ///
     /// For looping animations, this returns the number of times a single playback was completed. In the
@@ -180,13 +483,16 @@ pub trait Tweenable<T>: Send + Sync {
     fn rewind(&mut self);
 }
 
-impl<T> Tweenable<T> for Box<dyn Tweenable<T> + Send + Sync + 'static> {
+impl<T: 'static> Tweenable<T> for Box<dyn Tweenable<T> + Send + Sync + 'static> {
     fn duration(&self) -> Duration {
         self.as_ref().duration()
     }
     fn set_speed(&mut self, speed: f32) {
         self.as_mut().set_speed(speed);
     }
+    fn speed(&self) -> f32 {
+        self.as_ref().speed()
+    }
     fn is_looping(&self) -> bool {
         self.as_ref().is_looping()
     }
@@ -196,14 +502,28 @@ impl<T> Tweenable<T> for Box<dyn Tweenable<T> + Send + Sync + 'static> {
     fn progress(&self) -> f32 {
         self.as_ref().progress()
     }
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.as_mut().set_elapsed(elapsed);
+    }
+    fn elapsed(&self) -> Duration {
+        self.as_ref().elapsed()
+    }
     fn tick(
         &mut self,
         delta: Duration,
-        target: &mut T,
-        entity: Entity,
-        event_writer: &mut EventWriter<TweenCompleted>,
+        target: &mut dyn Targetable<T>,
+        completions: &mut dyn CompletionSink,
     ) -> TweenState {
-        self.as_mut().tick(delta, target, entity, event_writer)
+        self.as_mut().tick(delta, target, completions)
+    }
+    fn apply(&mut self, target: &mut T) {
+        self.as_mut().apply(target);
+    }
+    fn sample_at(&self, elapsed: Duration, target: &mut T) {
+        self.as_ref().sample_at(elapsed, target);
+    }
+    fn is_idle(&self) -> bool {
+        self.as_ref().is_idle()
     }
     fn times_completed(&self) -> u32 {
         self.as_ref().times_completed()
@@ -213,6 +533,36 @@ impl<T> Tweenable<T> for Box<dyn Tweenable<T> + Send + Sync + 'static> {
     }
 }
 
+/// Type-erased tweenable, as held by an [`Animator`] created with [`Animator::new()`].
+///
+/// [`Animator`]: crate::Animator
+/// [`Animator::new()`]: crate::Animator::new
+pub type BoxedTweenable<T> = Box<dyn Tweenable<T> + Send + Sync + 'static>;
+
+/// Clone a tweenable into a new [`BoxedTweenable`].
+///
+/// This trait is a supertrait of [`Tweenable`], and is implemented for all the tweenables which
+/// are [`Clone`]. It allows cloning a type-erased tweenable, and therefore a [`Sequence`], a
+/// [`Tracks`] or an [`Animator`] holding some. There is no need to implement it manually.
+///
+/// [`Animator`]: crate::Animator
+pub trait CloneTweenable<T> {
+    /// Clone the tweenable into a new boxed tweenable.
+    fn box_clone(&self) -> BoxedTweenable<T>;
+}
+
+impl<T, U: Tweenable<T> + Clone + 'static> CloneTweenable<T> for U {
+    fn box_clone(&self) -> BoxedTweenable<T> {
+        Box::new(self.clone())
+    }
+}
+
+impl<T> Clone for BoxedTweenable<T> {
+    fn clone(&self) -> Self {
+        (**self).box_clone()
+    }
+}
+
 /// Trait for boxing a [`Tweenable`] trait object.
 pub trait IntoBoxDynTweenable<T> {
     /// Convert the current object into a boxed [`Tweenable`].
@@ -227,22 +577,61 @@ impl<T, U: Tweenable<T> + Send + Sync + 'static> IntoBoxDynTweenable<T> for U {
 
 /// Type of a callback invoked when a [`Tween`] has completed.
 ///
+/// The callback receives the entity returned by [`CompletionSink::entity()`] for the sink the
+/// tween was ticked with. **When the tween is ticked manually with a sink which doesn't override
+/// it, like `()` or `Vec<u64>`, this is the placeholder [`Entity::from_raw(u32::MAX)`], not a
+/// valid entity.**
+///
 /// See [`Tween::set_completed()`] for usage.
-pub type CompletedCallback<T> = dyn Fn(Entity, &Tween<T>) + Send + Sync + 'static;
+///
+/// [`Entity::from_raw(u32::MAX)`]: Entity::from_raw
+pub type CompletedCallback<T, L = BoxedLens<T>> =
+    dyn Fn(Entity, &Tween<T, L>) + Send + Sync + 'static;
+
+/// Type-erased lens of a [`Tween`] created with [`Tween::new()`].
+pub type BoxedLens<T> = Box<dyn Lens<T> + Send + Sync + 'static>;
 
 /// Single tweening animation instance.
-pub struct Tween<T> {
+///
+/// The tween is generic over the type `L` of its lens. By default, [`Tween::new()`] creates a
+/// `Tween<T>` whose lens is boxed, which allows storing tweens with different lenses in the same
+/// collection. For large numbers of animations, [`Tween::new_static()`] instead stores the lens
+/// by value, avoiding a heap allocation per tween and a virtual call per tick.
+pub struct Tween<T, L = BoxedLens<T>> {
     ease_function: EaseMethod,
     clock: AnimClock,
+    speed: f32,
     times_completed: u32,
     tweening_type: TweeningType,
     direction: TweeningDirection,
-    lens: Box<dyn Lens<T> + Send + Sync + 'static>,
-    on_completed: Option<Box<CompletedCallback<T>>>,
+    lens: L,
+    last_factor: Option<f32>,
+    on_completed: Option<Arc<CompletedCallback<T, L>>>,
     event_data: Option<u64>,
 }
 
-impl<T: 'static> Tween<T> {
+impl<T, L: Clone> Clone for Tween<T, L> {
+    /// Clone the tween, including its current state and its completion callback, which is shared
+    /// with the original tween.
+    ///
+    /// The clone writes its current state to its target on its next tick, even if it didn't change.
+    fn clone(&self) -> Self {
+        Tween {
+            ease_function: self.ease_function,
+            clock: self.clock,
+            speed: self.speed,
+            times_completed: self.times_completed,
+            tweening_type: self.tweening_type,
+            direction: self.direction,
+            lens: self.lens.clone(),
+            last_factor: None,
+            on_completed: self.on_completed.clone(),
+            event_data: self.event_data,
+        }
+    }
+}
+
+impl<T: 'static, L: Lens<T> + Clone + Send + Sync + 'static> Tween<T, L> {
     /// Chain another [`Tweenable`] after this tween, making a [`Sequence`] with the two.
     ///
     /// # Example
@@ -270,14 +659,18 @@ impl<T: 'static> Tween<T> {
     /// );
     /// let seq = tween1.then(tween2);
     /// ```
-    pub fn then(self, tween: impl Tweenable<T> + Send + Sync + 'static) -> Sequence<T> {
+    pub fn then(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
         Sequence::with_capacity(2).then(self).then(tween)
     }
 }
 
-impl<T> Tween<T> {
+impl<T: 'static> Tween<T> {
     /// Create a new tween animation.
     ///
+    /// A tween with a zero `duration` jumps straight to its end state on its first tick. If it's
+    /// looping, it completes a single iteration per tick. Use [`Tween::try_new()`] to reject
+    /// zero-length looping tweens instead.
+    ///
     /// # Example
     /// ```
     /// # use bevy_tweening::{lens::*, *};
@@ -302,13 +695,65 @@ impl<T> Tween<T> {
     where
         L: Lens<T> + Send + Sync + 'static,
     {
+        Tween::new_static(ease_function, tweening_type, duration, Box::new(lens))
+    }
+
+    /// Create a new tween animation, or return [`TweeningError::ZeroDurationLoop`] if the tween
+    /// is looping with a zero `duration`.
+    ///
+    /// See [`Tween::new()`] for details.
+    pub fn try_new<L>(
+        ease_function: impl Into<EaseMethod>,
+        tweening_type: TweeningType,
+        duration: Duration,
+        lens: L,
+    ) -> Result<Self, TweeningError>
+    where
+        L: Lens<T> + Send + Sync + 'static,
+    {
+        if duration.is_zero() && tweening_type != TweeningType::Once {
+            return Err(TweeningError::ZeroDurationLoop);
+        }
+        Ok(Tween::new(ease_function, tweening_type, duration, lens))
+    }
+}
+
+impl<T: 'static, L: Lens<T> + Clone + Send + Sync + 'static> Tween<T, L> {
+    /// Create a new tween animation storing its lens by value.
+    ///
+    /// Unlike [`Tween::new()`], the type of the lens is part of the type of the tween. This
+    /// avoids boxing the lens, at the expense of a different tween type for each lens type.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::prelude::*;
+    /// # use std::time::Duration;
+    /// let tween: Tween<Transform, TransformPositionLens> = Tween::new_static(
+    ///     EaseFunction::QuadraticInOut,
+    ///     TweeningType::Once,
+    ///     Duration::from_secs_f32(1.0),
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::new(3.5, 0., 0.),
+    ///     },
+    /// );
+    /// ```
+    pub fn new_static(
+        ease_function: impl Into<EaseMethod>,
+        tweening_type: TweeningType,
+        duration: Duration,
+        lens: L,
+    ) -> Self {
         Tween {
             ease_function: ease_function.into(),
             clock: AnimClock::new(duration, tweening_type != TweeningType::Once),
+            speed: 1.,
             times_completed: 0,
             tweening_type,
             direction: TweeningDirection::Forward,
-            lens: Box::new(lens),
+            lens,
+            last_factor: None,
             on_completed: None,
             event_data: None,
         }
@@ -391,12 +836,19 @@ impl<T> Tween<T> {
     /// The callback when invoked receives as parameters the [`Entity`] on which the target and the
     /// animator are, as well as a reference to the current [`Tween`].
     ///
+    /// **When the tween is ticked manually with a [`CompletionSink`] which doesn't override
+    /// [`CompletionSink::entity()`], like `()` or `Vec<u64>`, the entity is the placeholder
+    /// [`Entity::from_raw(u32::MAX)`], which doesn't refer to any animated entity.** Sinks
+    /// created with [`CompletedEvents::new()`] pass their entity.
+    ///
     /// Only non-looping tweenables can complete.
+    ///
+    /// [`Entity::from_raw(u32::MAX)`]: Entity::from_raw
     pub fn set_completed<C>(&mut self, callback: C)
     where
-        C: Fn(Entity, &Tween<T>) + Send + Sync + 'static,
+        C: Fn(Entity, &Tween<T, L>) + Send + Sync + 'static,
     {
-        self.on_completed = Some(Box::new(callback));
+        self.on_completed = Some(Arc::new(callback));
     }
 
     /// Clear the callback invoked when the animation completed.
@@ -416,17 +868,110 @@ impl<T> Tween<T> {
     pub fn set_completed_event(&mut self, enabled: bool, user_data: u64) {
         self.event_data = if enabled { Some(user_data) } else { None };
     }
+
+    /// Interpolation factor of the current state, passed to the lens.
+    fn factor(&self) -> f32 {
+        self.factor_at(self.clock.progress(), self.direction)
+    }
+
+    /// Interpolation factor for the given iteration progress and direction.
+    fn factor_at(&self, progress: f32, direction: TweeningDirection) -> f32 {
+        let factor = if direction.is_backward() {
+            1. - progress
+        } else {
+            progress
+        };
+        self.ease_function.sample(factor)
+    }
+
+    /// Whether the tween changes direction each time it completes.
+    fn is_ping_pong(&self) -> bool {
+        self.tweening_type == TweeningType::PingPong
+            || matches!(self.tweening_type, TweeningType::PingPongTimes(_))
+    }
+
+    /// Direction the tween started with, before any ping-pong completion.
+    fn start_direction(&self) -> TweeningDirection {
+        if self.is_ping_pong() && self.times_completed & 1 != 0 {
+            !self.direction
+        } else {
+            self.direction
+        }
+    }
+
+    /// Number of completed iterations and elapsed time of the current one, once the tween
+    /// played for `elapsed` from its start.
+    fn seek(&self, elapsed: Duration) -> (u32, Duration) {
+        let duration = self.clock.duration;
+        match self.tweening_type {
+            TweeningType::Once if elapsed >= duration => (1, duration),
+            TweeningType::Once => (0, elapsed),
+            TweeningType::LoopTimes(times) | TweeningType::PingPongTimes(times)
+                if elapsed >= duration * times =>
+            {
+                // Like ticking, the clock loops back to the start of an iteration on completion
+                (times, Duration::ZERO)
+            }
+            _ if duration.is_zero() => (0, Duration::ZERO),
+            _ => {
+                // Use integer nanoseconds to count loops exactly, even after many iterations
+                let (elapsed, duration) = (elapsed.as_nanos(), duration.as_nanos());
+                let times = (elapsed / duration).min(u32::MAX as u128) as u32;
+                (times, Duration::from_nanos((elapsed % duration) as u64))
+            }
+        }
+    }
+
+    /// Write the current state to the target, unless the last tick already wrote it.
+    fn apply_changed(&mut self, target: &mut dyn Targetable<T>) {
+        let factor = self.factor();
+        if self.last_factor != Some(factor) {
+            self.lens.lerp(target.target_mut(), factor);
+            self.last_factor = Some(factor);
+        }
+    }
+
+    /// Move the tween backward in time by `delta`, toward its start.
+    fn tick_backward(&mut self, delta: Duration, target: &mut dyn Targetable<T>) -> TweenState {
+        let duration = self.clock.duration.as_secs_f32();
+        let mut progress = if duration > 0. {
+            self.clock.progress() - delta.as_secs_f32() / duration
+        } else {
+            0.
+        };
+        let mut state = TweenState::Active;
+        if progress <= 0. {
+            if self.is_looping() {
+                // Crossing the start of an iteration moves back to the end of the previous one,
+                // which was played in the other direction for ping-pong tweens.
+                let crossings = (-progress).floor() as u32 + 1;
+                progress = progress.rem_euclid(1.);
+                self.times_completed = self.times_completed.saturating_sub(crossings);
+                if crossings & 1 != 0 && self.is_ping_pong() {
+                    self.direction = !self.direction;
+                }
+            } else {
+                progress = 0.;
+                state = TweenState::Completed;
+            }
+        }
+        self.clock.set_progress(progress);
+        self.apply_changed(target);
+        state
+    }
 }
 
-impl<T> Tweenable<T> for Tween<T> {
+impl<T: 'static, L: Lens<T> + Clone + Send + Sync + 'static> Tweenable<T> for Tween<T, L> {
     fn duration(&self) -> Duration {
         self.clock.duration
     }
 
     fn set_speed(&mut self, speed: f32) {
-        let progress = self.progress();
-        self.clock.duration = self.clock.original.mul_f32(speed);
-        self.set_progress(progress);
+        self.speed = speed;
+    }
+
+    fn speed(&self) -> f32 {
+        self.speed
     }
 
     fn is_looping(&self) -> bool {
@@ -441,30 +986,62 @@ impl<T> Tweenable<T> for Tween<T> {
 
     fn set_progress(&mut self, progress: f32) {
         self.clock.set_progress(progress);
+        self.last_factor = None;
     }
 
     fn progress(&self) -> f32 {
         self.clock.progress()
     }
 
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        let direction = self.start_direction();
+        let (times_completed, elapsed) = self.seek(elapsed);
+        self.times_completed = times_completed;
+        self.clock.elapsed = elapsed;
+        self.last_factor = None;
+        self.direction = if self.is_ping_pong() && times_completed & 1 != 0 {
+            !direction
+        } else {
+            direction
+        };
+    }
+
+    fn elapsed(&self) -> Duration {
+        match self.tweening_type {
+            TweeningType::Once => self.clock.elapsed,
+            TweeningType::LoopTimes(times) | TweeningType::PingPongTimes(times)
+                if self.times_completed >= times =>
+            {
+                self.clock.duration * times
+            }
+            _ => self.clock.duration * self.times_completed + self.clock.elapsed,
+        }
+    }
+
     fn tick(
         &mut self,
         delta: Duration,
-        target: &mut T,
-        entity: Entity,
-        event_writer: &mut EventWriter<TweenCompleted>,
+        target: &mut dyn Targetable<T>,
+        completions: &mut dyn CompletionSink,
     ) -> TweenState {
-        if !self.is_looping() && self.clock.completed() {
+        if self.speed < 0. {
+            return self.tick_backward(delta.mul_f32(-self.speed), target);
+        }
+
+        // A zero-length tween is always at its end, but still needs a tick to complete
+        if !self.is_looping()
+            && self.clock.completed()
+            && (self.times_completed > 0 || !self.clock.duration.is_zero())
+        {
+            // Write the final state if not already done, for example after set_progress()
+            self.apply_changed(target);
             return TweenState::Completed;
         }
 
         // Tick the animation clock
-        let times_completed = self.clock.tick(delta);
+        let times_completed = self.clock.tick(delta.mul_f32(self.speed));
         self.times_completed += times_completed;
-        if times_completed & 1 != 0
-            && (self.tweening_type == TweeningType::PingPong
-                || matches!(self.tweening_type, TweeningType::PingPongTimes(_)))
-        {
+        if times_completed & 1 != 0 && self.is_ping_pong() {
             self.direction = !self.direction;
         }
         let state = if self.is_looping() || self.times_completed == 0 {
@@ -472,32 +1049,52 @@ impl<T> Tweenable<T> for Tween<T> {
         } else {
             TweenState::Completed
         };
-        let progress = self.clock.progress();
 
-        // Apply the lens, even if the animation finished, to ensure the state is consistent
-        let mut factor = progress;
-        if self.direction.is_backward() {
-            factor = 1. - factor;
-        }
-        let factor = self.ease_function.sample(factor);
-        self.lens.lerp(target, factor);
+        // Apply the lens, even if the animation finished, to ensure the state is consistent. Skip it
+        // if the value didn't change, to avoid writing to the target.
+        self.apply_changed(target);
 
         // If completed at least once this frame, notify the user
         if times_completed > 0 {
             if let Some(user_data) = &self.event_data {
-                event_writer.send(TweenCompleted {
-                    entity,
-                    user_data: *user_data,
-                });
+                completions.completed(*user_data);
             }
             if let Some(cb) = &self.on_completed {
-                cb(entity, self);
+                cb(completions.entity(), self);
             }
         }
 
         state
     }
 
+    fn apply(&mut self, target: &mut T) {
+        let factor = self.factor();
+        self.lens.lerp(target, factor);
+    }
+
+    fn sample_at(&self, elapsed: Duration, target: &mut T) {
+        let (times_completed, elapsed) = self.seek(elapsed);
+        let mut direction = self.start_direction();
+        if self.is_ping_pong() && times_completed & 1 != 0 {
+            direction = !direction;
+        }
+        let progress = progress(elapsed, self.clock.duration);
+        // Lenses may be stateful, so sample on a scratch copy to leave this one untouched.
+        let mut lens = self.lens.clone();
+        lens.lerp(target, self.factor_at(progress, direction));
+    }
+
+    fn is_idle(&self) -> bool {
+        let frozen = if self.speed > 0. {
+            !self.is_looping() && self.clock.completed()
+        } else if self.speed < 0. {
+            !self.is_looping() && self.clock.elapsed.is_zero()
+        } else {
+            true
+        };
+        frozen && self.last_factor == Some(self.factor())
+    }
+
     fn times_completed(&self) -> u32 {
         self.times_completed
     }
@@ -505,6 +1102,7 @@ impl<T> Tweenable<T> for Tween<T> {
     fn rewind(&mut self) {
         self.clock.reset();
         self.times_completed = 0;
+        self.last_factor = None;
     }
 }
 
@@ -514,35 +1112,66 @@ pub struct Sequence<T> {
     index: usize,
     duration: Duration,
     elapsed: Duration,
+    speed: f32,
+    applied: bool,
+}
+
+impl<T> Clone for Sequence<T> {
+    fn clone(&self) -> Self {
+        Sequence {
+            tweens: self.tweens.clone(),
+            index: self.index,
+            duration: self.duration,
+            elapsed: self.elapsed,
+            speed: self.speed,
+            applied: false,
+        }
+    }
 }
 
-impl<T> Sequence<T> {
+impl<T: 'static> Sequence<T> {
     /// Create a new sequence of tweens.
     ///
-    /// This method panics if the input collection is empty.
+    /// An empty sequence has a zero duration, and completes on its first tick. Use
+    /// [`Sequence::try_new()`] to reject empty collections instead.
     pub fn new(items: impl IntoIterator<Item = impl IntoBoxDynTweenable<T>>) -> Self {
         let tweens: Vec<_> = items
             .into_iter()
             .map(IntoBoxDynTweenable::into_box_dyn)
             .collect();
-        assert!(!tweens.is_empty());
         let duration = tweens.iter().map(|t| t.duration()).sum();
         Sequence {
             tweens,
             index: 0,
             duration,
             elapsed: Duration::ZERO,
+            speed: 1.,
+            applied: false,
+        }
+    }
+
+    /// Create a new sequence of tweens, or return [`TweeningError::EmptySequence`] if the input
+    /// collection is empty.
+    pub fn try_new(
+        items: impl IntoIterator<Item = impl IntoBoxDynTweenable<T>>,
+    ) -> Result<Self, TweeningError> {
+        let sequence = Sequence::new(items);
+        if sequence.tweens.is_empty() {
+            return Err(TweeningError::EmptySequence);
         }
+        Ok(sequence)
     }
 
     /// Create a new sequence containing a single tween.
-    pub fn from_single(tween: impl Tweenable<T> + Send + Sync + 'static) -> Self {
+    pub fn from_single(tween: impl Tweenable<T> + 'static) -> Self {
         let duration = tween.duration();
         Sequence {
             tweens: vec![Box::new(tween)],
             index: 0,
             duration,
             elapsed: Duration::ZERO,
+            speed: 1.,
+            applied: false,
         }
     }
 
@@ -553,36 +1182,72 @@ impl<T> Sequence<T> {
             index: 0,
             duration: Duration::ZERO,
             elapsed: Duration::ZERO,
+            speed: 1.,
+            applied: false,
         }
     }
 
     /// Append a [`Tweenable`] to this sequence.
-    pub fn then(mut self, tween: impl Tweenable<T> + Send + Sync + 'static) -> Self {
+    pub fn then(mut self, tween: impl Tweenable<T> + 'static) -> Self {
         self.duration += tween.duration();
         self.tweens.push(Box::new(tween));
         self
     }
 
     /// Index of the current active tween in the sequence.
-    pub fn index(&self) -> usize {
-        self.index.min(self.tweens.len() - 1)
+    ///
+    /// Once the sequence completed, this is the index of its last tween. Returns `None` if the
+    /// sequence is empty.
+    pub fn index(&self) -> Option<usize> {
+        self.tweens
+            .len()
+            .checked_sub(1)
+            .map(|last| self.index.min(last))
     }
 
     /// Get the current active tween in the sequence.
-    pub fn current(&self) -> &dyn Tweenable<T> {
-        self.tweens[self.index()].as_ref()
+    ///
+    /// Returns `None` if the sequence is empty.
+    pub fn current(&self) -> Option<&dyn Tweenable<T>> {
+        let index = self.index()?;
+        Some(self.tweens[index].as_ref())
+    }
+
+    /// Move the sequence backward in time by `delta`, toward its start.
+    fn tick_backward(&mut self, delta: Duration, target: &mut dyn Targetable<T>) -> TweenState {
+        let elapsed = self.elapsed.saturating_sub(delta);
+        let prev_end = min(self.index + 1, self.tweens.len());
+        self.set_elapsed(elapsed);
+
+        // Restore the start state of the tweenables moved back over, latest first, so that
+        // earlier tweenables take precedence.
+        let start = min(self.index + 1, prev_end);
+        let target = target.target_mut();
+        for tween in self.tweens[start..prev_end].iter_mut().rev() {
+            tween.apply(target);
+        }
+        self.apply(target);
+        self.applied = true;
+
+        if elapsed.is_zero() {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
     }
 }
 
-impl<T> Tweenable<T> for Sequence<T> {
+impl<T: 'static> Tweenable<T> for Sequence<T> {
     fn duration(&self) -> Duration {
         self.duration
     }
 
     fn set_speed(&mut self, speed: f32) {
-        for tween in &mut self.tweens {
-            tween.set_speed(speed);
-        }
+        self.speed = speed;
+    }
+
+    fn speed(&self) -> f32 {
+        self.speed
     }
 
     fn is_looping(&self) -> bool {
@@ -590,70 +1255,60 @@ impl<T> Tweenable<T> for Sequence<T> {
     }
 
     fn set_progress(&mut self, progress: f32) {
-        // Optimize the boundary conditions
-        if progress < 1e-5 {
-            self.rewind();
-            return;
-        } else if progress > 1. - 1e-5 {
-            self.elapsed = self.duration;
-            self.index = self.tweens.len();
-            return;
-        }
+        self.set_elapsed(self.duration.mul_f32(progress.clamp(0., 1.)));
+    }
 
-        self.elapsed = self.duration.mul_f32(progress.clamp(0., 1.));
-        let mut delta = self.elapsed.as_secs_f32();
+    fn progress(&self) -> f32 {
+        progress(self.elapsed, self.duration)
+    }
 
-        // Use self.index to optimize out set_progress calls
-        let mut index = 0;
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.min(self.duration);
+        self.applied = false;
+        let mut remaining = self.elapsed;
 
-        for tween in &mut self.tweens {
-            let tween_duration = tween.duration().as_secs_f32();
-            let tween_delta = tween_duration - delta;
-
-            if tween_delta < -1e-5 {
-                // Fully complete tween
-                if index >= self.index {
-                    tween.set_progress(1.);
-                }
-            } else {
-                if tween_delta > 1e-5 {
-                    // Partially complete tween
-                    tween.set_progress(tween_delta / tween_duration);
-                } else {
-                    // We're right on the boundary of completing this tween, so mark it complete.
-                    if index >= self.index {
-                        tween.set_progress(1.);
-                    }
-                    index += 1;
-                }
-
-                break;
-            }
-
-            index += 1;
-            delta -= tween_duration;
-        }
-
-        if index < self.index {
-            let end = min(self.index + 1, self.tweens.len());
-            for tween in &mut self.tweens[index + 1..end] {
+        // Complete all tweenables before the current one, and rewind all the ones after it.
+        // Snap to the end of a tweenable when within floating-point errors of it.
+        let len = self.tweens.len();
+        let mut index = len;
+        for (i, tween) in self.tweens.iter_mut().enumerate() {
+            let tween_duration = tween.duration();
+            if index < len {
                 tween.rewind();
+            } else if remaining + Duration::from_micros(10) < tween_duration {
+                tween.set_elapsed(remaining);
+                index = i;
+            } else {
+                tween.set_elapsed(tween_duration);
+                remaining = remaining.saturating_sub(tween_duration);
             }
         }
         self.index = index;
     }
 
-    fn progress(&self) -> f32 {
-        self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+    fn elapsed(&self) -> Duration {
+        self.elapsed
     }
 
     fn tick(
         &mut self,
-        mut delta: Duration,
-        target: &mut T,
-        entity: Entity,
-        event_writer: &mut EventWriter<TweenCompleted>,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        completions: &mut dyn CompletionSink,
     ) -> TweenState {
+        if self.speed < 0. {
+            return self.tick_backward(delta.mul_f32(-self.speed), target);
+        }
+
+        // After a seek, the tweenables before the current one are not ticked anymore, so write
+        // their end state once
+        if !self.applied {
+            for tween in &mut self.tweens[..self.index] {
+                tween.apply(target.target_mut());
+            }
+        }
+
+        let mut delta = delta.mul_f32(self.speed);
         self.elapsed = min(self.elapsed + delta, self.duration);
 
         let len = self.tweens.len();
@@ -662,11 +1317,14 @@ impl<T> Tweenable<T> for Sequence<T> {
             let prev_progress = tween.progress();
             let prev_completions = tween.times_completed();
 
-            state = tween.tick(delta, target, entity, event_writer);
-            if state != TweenState::Completed {
+            state = tween.tick(delta, target, completions);
+            let tween_speed = tween.speed();
+            if state != TweenState::Completed || tween_speed < 0. {
                 // If we completed zero times, then that means the entire delta was used up on this
                 // tween. Otherwise, we need to diff the tween progress because it overlaps the
-                // completion boundary.
+                // completion boundary. A tween playing backward completes at its start, so the
+                // sequence can't move on to the next one.
+                state = TweenState::Active;
                 break;
             }
             self.index += 1;
@@ -675,13 +1333,15 @@ impl<T> Tweenable<T> for Sequence<T> {
                 break;
             }
 
+            // Convert the time used by the tween at its own speed back into sequence time
             let tween_duration = tween.duration();
-
-            let full_completions =
-                (tween.times_completed() - prev_completions - 1) * tween_duration;
-            delta -= full_completions;
-
-            let used_delta = tween_duration.mul_f32(1. - prev_progress);
+            let used_delta = if tween_speed > 0. {
+                let full_completions =
+                    (tween.times_completed() - prev_completions).saturating_sub(1) * tween_duration;
+                (full_completions + tween_duration.mul_f32(1. - prev_progress)).div_f32(tween_speed)
+            } else {
+                Duration::ZERO
+            };
             if let Some(new_delta) = delta.checked_sub(used_delta) {
                 delta = new_delta;
             } else {
@@ -691,9 +1351,47 @@ impl<T> Tweenable<T> for Sequence<T> {
                 break;
             }
         }
+        self.applied = true;
         state
     }
 
+    fn apply(&mut self, target: &mut T) {
+        // Tweenables after the current one didn't start yet
+        let end = min(self.index + 1, self.tweens.len());
+        for tween in &mut self.tweens[..end] {
+            tween.apply(target);
+        }
+    }
+
+    fn sample_at(&self, elapsed: Duration, target: &mut T) {
+        // Like set_elapsed(), snap to the end of a tweenable when within floating-point errors of
+        // it, and skip the tweenables after the one playing at that time.
+        let mut remaining = elapsed.min(self.duration);
+        for tween in &self.tweens {
+            let tween_duration = tween.duration();
+            if remaining + Duration::from_micros(10) < tween_duration {
+                tween.sample_at(remaining, target);
+                break;
+            }
+            tween.sample_at(tween_duration, target);
+            remaining = remaining.saturating_sub(tween_duration);
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        if !self.applied {
+            // A seek or rewind leaves the target out of date until the next tick
+            false
+        } else if self.speed < 0. {
+            self.elapsed.is_zero()
+        } else if self.speed == 0. {
+            true
+        } else {
+            // Only the current tweenable is ticked
+            self.index == self.tweens.len() || self.tweens[self.index].is_idle()
+        }
+    }
+
     fn times_completed(&self) -> u32 {
         if self.index == self.tweens.len() {
             1
@@ -705,6 +1403,7 @@ impl<T> Tweenable<T> for Sequence<T> {
     fn rewind(&mut self) {
         self.elapsed = Duration::ZERO;
         self.index = 0;
+        self.applied = false;
         for tween in &mut self.tweens {
             tween.rewind();
         }
@@ -717,34 +1416,85 @@ pub struct Tracks<T> {
     duration: Duration,
     elapsed: Duration,
     completed: bool,
+    speed: f32,
+    applied: bool,
+}
+
+impl<T> Clone for Tracks<T> {
+    fn clone(&self) -> Self {
+        Tracks {
+            tracks: self.tracks.clone(),
+            duration: self.duration,
+            elapsed: self.elapsed,
+            completed: self.completed,
+            speed: self.speed,
+            applied: false,
+        }
+    }
 }
 
-impl<T> Tracks<T> {
+impl<T: 'static> Tracks<T> {
     /// Create a new [`Tracks`] from an iterator over a collection of [`Tweenable`].
+    ///
+    /// Empty tracks have a zero duration, and complete on their first tick. Use
+    /// [`Tracks::try_new()`] to reject empty collections instead.
     pub fn new(items: impl IntoIterator<Item = impl IntoBoxDynTweenable<T>>) -> Self {
         let tracks: Vec<_> = items
             .into_iter()
             .map(IntoBoxDynTweenable::into_box_dyn)
             .collect();
-        let duration = tracks.iter().map(|t| t.duration()).max().unwrap();
+        let duration = tracks
+            .iter()
+            .map(|t| t.duration())
+            .max()
+            .unwrap_or(Duration::ZERO);
         Tracks {
             tracks,
             duration,
             elapsed: Duration::ZERO,
             completed: false,
+            speed: 1.,
+            applied: false,
+        }
+    }
+
+    /// Create a new [`Tracks`] from an iterator over a collection of [`Tweenable`], or return
+    /// [`TweeningError::EmptyTracks`] if the collection is empty.
+    pub fn try_new(
+        items: impl IntoIterator<Item = impl IntoBoxDynTweenable<T>>,
+    ) -> Result<Self, TweeningError> {
+        let tracks = Tracks::new(items);
+        if tracks.tracks.is_empty() {
+            return Err(TweeningError::EmptyTracks);
+        }
+        Ok(tracks)
+    }
+
+    /// Move the tracks backward in time by `delta`, toward their start.
+    fn tick_backward(&mut self, delta: Duration, target: &mut dyn Targetable<T>) -> TweenState {
+        let elapsed = self.elapsed.saturating_sub(delta);
+        self.set_elapsed(elapsed);
+        self.apply(target.target_mut());
+        self.applied = true;
+        if elapsed.is_zero() {
+            TweenState::Completed
+        } else {
+            TweenState::Active
         }
     }
 }
 
-impl<T> Tweenable<T> for Tracks<T> {
+impl<T: 'static> Tweenable<T> for Tracks<T> {
     fn duration(&self) -> Duration {
         self.duration
     }
 
     fn set_speed(&mut self, speed: f32) {
-        for tween in &mut self.tracks {
-            tween.set_speed(speed);
-        }
+        self.speed = speed;
+    }
+
+    fn speed(&self) -> f32 {
+        self.speed
     }
 
     fn is_looping(&self) -> bool {
@@ -752,36 +1502,76 @@ impl<T> Tweenable<T> for Tracks<T> {
     }
 
     fn set_progress(&mut self, progress: f32) {
-        self.elapsed = self.duration.mul_f32(progress.clamp(0., 1.));
-        let elapsed = self.elapsed.as_secs_f32();
+        self.set_elapsed(self.duration.mul_f32(progress.clamp(0., 1.)));
+    }
+
+    fn progress(&self) -> f32 {
+        progress(self.elapsed, self.duration)
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.min(self.duration);
+        self.completed = self.elapsed >= self.duration;
+        self.applied = false;
         for tweenable in &mut self.tracks {
-            tweenable.set_progress(elapsed / tweenable.duration().as_secs_f32());
+            tweenable.set_elapsed(self.elapsed);
         }
     }
 
-    fn progress(&self) -> f32 {
-        self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+    fn elapsed(&self) -> Duration {
+        self.elapsed
     }
 
     fn tick(
         &mut self,
         delta: Duration,
-        target: &mut T,
-        entity: Entity,
-        event_writer: &mut EventWriter<TweenCompleted>,
+        target: &mut dyn Targetable<T>,
+        completions: &mut dyn CompletionSink,
     ) -> TweenState {
+        if self.speed < 0. {
+            return self.tick_backward(delta.mul_f32(-self.speed), target);
+        }
+
+        let delta = delta.mul_f32(self.speed);
         self.elapsed = min(self.elapsed + delta, self.duration);
 
         let mut state = TweenState::Completed;
         for tweenable in &mut self.tracks {
-            if tweenable.tick(delta, target, entity, event_writer) == TweenState::Active {
+            if tweenable.tick(delta, target, completions) == TweenState::Active {
                 state = TweenState::Active;
             }
         }
         self.completed = state == TweenState::Completed;
+        self.applied = true;
         state
     }
 
+    fn apply(&mut self, target: &mut T) {
+        for tweenable in &mut self.tracks {
+            tweenable.apply(target);
+        }
+    }
+
+    fn sample_at(&self, elapsed: Duration, target: &mut T) {
+        let elapsed = elapsed.min(self.duration);
+        for tweenable in &self.tracks {
+            tweenable.sample_at(elapsed, target);
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        if !self.applied {
+            // A seek or rewind leaves the target out of date until the next tick
+            false
+        } else if self.speed < 0. {
+            self.elapsed.is_zero()
+        } else if self.speed == 0. {
+            true
+        } else {
+            self.tracks.iter().all(|tweenable| tweenable.is_idle())
+        }
+    }
+
     fn times_completed(&self) -> u32 {
         if self.completed {
             1
@@ -793,6 +1583,7 @@ impl<T> Tweenable<T> for Tracks<T> {
     fn rewind(&mut self) {
         self.elapsed = Duration::ZERO;
         self.completed = false;
+        self.applied = false;
         for tween in &mut self.tracks {
             tween.rewind();
         }
@@ -804,9 +1595,10 @@ impl<T> Tweenable<T> for Tracks<T> {
 /// This is generally useful for combining with other tweenables into sequences and tracks,
 /// for example to delay the start of a tween in a track relative to another track. The `menu`
 /// example (`examples/menu.rs`) uses this technique to delay the animation of its buttons.
+#[derive(Clone)]
 pub struct Delay {
     timer: Timer,
-    original: Duration,
+    speed: f32,
 }
 
 impl Delay {
@@ -814,12 +1606,12 @@ impl Delay {
     pub fn new(duration: Duration) -> Self {
         Delay {
             timer: Timer::new(duration, false),
-            original: duration,
+            speed: 1.,
         }
     }
 
     /// Chain another [`Tweenable`] after this tween, making a sequence with the two.
-    pub fn then<T>(self, tween: impl Tweenable<T> + Send + Sync + 'static) -> Sequence<T> {
+    pub fn then<T: 'static>(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
         Sequence::with_capacity(2).then(self).then(tween)
     }
 }
@@ -830,7 +1622,11 @@ impl<T> Tweenable<T> for Delay {
     }
 
     fn set_speed(&mut self, speed: f32) {
-        self.timer.set_duration(self.original.mul_f32(speed));
+        self.speed = speed;
+    }
+
+    fn speed(&self) -> f32 {
+        self.speed
     }
 
     fn is_looping(&self) -> bool {
@@ -843,17 +1639,49 @@ impl<T> Tweenable<T> for Delay {
     }
 
     fn progress(&self) -> f32 {
-        self.timer.percent()
+        progress(self.timer.elapsed(), self.timer.duration())
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.timer.reset();
+        self.timer.tick(elapsed);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+
+    fn is_idle(&self) -> bool {
+        if self.speed > 0. {
+            self.timer.finished()
+        } else if self.speed < 0. {
+            self.timer.elapsed().is_zero()
+        } else {
+            true
+        }
     }
 
     fn tick(
         &mut self,
         delta: Duration,
-        _target: &mut T,
-        _entity: Entity,
-        _event_writer: &mut EventWriter<TweenCompleted>,
+        _target: &mut dyn Targetable<T>,
+        _completions: &mut dyn CompletionSink,
     ) -> TweenState {
-        self.timer.tick(delta);
+        if self.speed < 0. {
+            let elapsed = self
+                .timer
+                .elapsed()
+                .saturating_sub(delta.mul_f32(-self.speed));
+            self.timer.reset();
+            self.timer.tick(elapsed);
+            return if elapsed.is_zero() {
+                TweenState::Completed
+            } else {
+                TweenState::Active
+            };
+        }
+
+        self.timer.tick(delta.mul_f32(self.speed));
         if self.timer.finished() {
             TweenState::Completed
         } else {
@@ -879,7 +1707,7 @@ mod tests {
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
-    use bevy::ecs::{event::Events, system::SystemState};
+    use bevy::ecs::event::Events;
     use itertools::Itertools;
 
     use crate::lens::*;
@@ -949,13 +1777,8 @@ mod tests {
                 assert!(tween.event_data.is_some());
                 assert_eq!(tween.event_data.unwrap(), USER_DATA);
 
-                // Dummy world and event writer
-                let mut world = World::new();
-                world.insert_resource(Events::<TweenCompleted>::default());
-                let mut event_writer_system_state: SystemState<EventWriter<TweenCompleted>> =
-                    SystemState::new(&mut world);
-                let mut event_reader_system_state: SystemState<EventReader<TweenCompleted>> =
-                    SystemState::new(&mut world);
+                // Completion events, sent without any world
+                let mut events = Events::<TweenCompleted>::default();
 
                 // Loop over 2.2 seconds, so greater than one ping-pong loop
                 let mut transform = Transform::default();
@@ -1040,22 +1863,11 @@ mod tests {
                     );
 
                     // Tick the tween
-                    let actual_state = {
-                        let mut event_writer = event_writer_system_state.get_mut(&mut world);
-                        tween.tick(
-                            tick_duration,
-                            &mut transform,
-                            dummy_entity,
-                            &mut event_writer,
-                        )
-                    };
-
-                    // Propagate events
-                    {
-                        let mut events =
-                            world.get_resource_mut::<Events<TweenCompleted>>().unwrap();
-                        events.update();
-                    }
+                    let actual_state = tween.tick(
+                        tick_duration,
+                        &mut transform,
+                        &mut CompletedEvents::new(dummy_entity, &mut events),
+                    );
 
                     // Check actual values
                     assert_eq!(tween.direction(), direction);
@@ -1080,8 +1892,9 @@ mod tests {
                     assert_eq!(cb_mon.invoke_count, times_completed as u64);
                     assert_eq!(cb_mon.last_reported_count, times_completed);
                     {
-                        let mut event_reader = event_reader_system_state.get_mut(&mut world);
-                        let event = event_reader.iter().next();
+                        let mut events = events.drain();
+                        let event = events.next();
+                        assert!(events.next().is_none());
                         if just_completed {
                             assert!(event.is_some());
                             if let Some(event) = event {
@@ -1102,15 +1915,7 @@ mod tests {
                 assert_eq!(tween.times_completed(), 0);
 
                 // Dummy tick to update target
-                let actual_state = {
-                    let mut event_writer = event_writer_system_state.get_mut(&mut world);
-                    tween.tick(
-                        Duration::ZERO,
-                        &mut transform,
-                        Entity::from_raw(0),
-                        &mut event_writer,
-                    )
-                };
+                let actual_state = tween.tick(Duration::ZERO, &mut transform, &mut ());
                 assert_eq!(actual_state, TweenState::Active);
                 let expected_translation = if tweening_direction.is_backward() {
                     Vec3::ONE
@@ -1164,28 +1969,607 @@ mod tests {
         // progress is independent of direction
         assert!(abs_diff_eq(tween.progress(), 0.3, 1e-5));
 
-        // Dummy world and event writer
-        let mut world = World::new();
-        world.insert_resource(Events::<TweenCompleted>::default());
-        let mut event_writer_system_state: SystemState<EventWriter<TweenCompleted>> =
-            SystemState::new(&mut world);
-
         // Progress always increases alongside the current direction
-        let dummy_entity = Entity::from_raw(0);
         let mut transform = Transform::default();
-        let mut event_writer = event_writer_system_state.get_mut(&mut world);
         tween.set_direction(TweeningDirection::Backward);
         assert!(abs_diff_eq(tween.progress(), 0.3, 1e-5));
-        tween.tick(
-            Duration::from_secs_f32(0.1),
-            &mut transform,
-            dummy_entity,
-            &mut event_writer,
-        );
+        tween.tick(Duration::from_secs_f32(0.1), &mut transform, &mut ());
         assert!(abs_diff_eq(tween.progress(), 0.4, 1e-5));
         assert!(transform.translation.abs_diff_eq(Vec3::splat(0.6), 1e-5));
     }
 
+    /// Test that ticking only writes to the target when the animated value changes.
+    #[test]
+    fn tween_idle() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut seq = Delay::new(Duration::from_secs(1)).then(tween);
+
+        let mut transform = Transform::default();
+        let tick = |seq: &mut Sequence<Transform>, transform: &mut Transform, secs: f32| {
+            seq.tick(Duration::from_secs_f32(secs), transform, &mut ())
+        };
+
+        // A running delay is not idle, even if it doesn't write anything
+        assert!(!seq.is_idle());
+        tick(&mut seq, &mut transform, 1.5);
+        assert!(!seq.is_idle());
+        tick(&mut seq, &mut transform, 1.);
+        assert!(seq.is_idle());
+
+        // The final state is not written again
+        transform.translation = Vec3::splat(5.);
+        tick(&mut seq, &mut transform, 1.);
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(5.), 1e-5));
+
+        // Seeking writes the new state on next tick
+        seq.set_progress(0.75);
+        assert!(!seq.is_idle());
+        tick(&mut seq, &mut transform, 0.);
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+
+        // Frozen animations are idle once their current state is written
+        seq.set_speed(0.);
+        assert!(seq.is_idle());
+        transform.translation = Vec3::ZERO;
+        tick(&mut seq, &mut transform, 1.);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+    }
+
+    /// Test a tween storing its lens by value behaves like a boxed one.
+    #[test]
+    fn tween_static() {
+        let lens = TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::ONE,
+        };
+        let mut boxed = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::PingPong,
+            Duration::from_secs(1),
+            lens,
+        );
+        let mut unboxed: Tween<Transform, TransformPositionLens> = Tween::new_static(
+            EaseMethod::Linear,
+            TweeningType::PingPong,
+            Duration::from_secs(1),
+            lens,
+        );
+
+        let mut transform_boxed = Transform::default();
+        let mut transform_unboxed = Transform::default();
+        for _ in 0..6 {
+            let delta = Duration::from_secs_f32(0.4);
+            let state_boxed = boxed.tick(delta, &mut transform_boxed, &mut ());
+            let state_unboxed = unboxed.tick(delta, &mut transform_unboxed, &mut ());
+            assert_eq!(state_boxed, state_unboxed);
+            assert_eq!(boxed.times_completed(), unboxed.times_completed());
+            assert!(abs_diff_eq(boxed.progress(), unboxed.progress(), 1e-5));
+            assert!(transform_boxed
+                .translation
+                .abs_diff_eq(transform_unboxed.translation, 1e-5));
+        }
+    }
+
+    /// Test seeking a tween by absolute time.
+    #[test]
+    fn tween_elapsed() {
+        let tween = |tweening_type| {
+            Tween::new(
+                EaseMethod::Linear,
+                tweening_type,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+
+        // Non-looping tweens clamp to their duration
+        let mut once = tween(TweeningType::Once);
+        once.set_elapsed(Duration::from_millis(300));
+        assert_eq!(once.elapsed(), Duration::from_millis(300));
+        assert!(abs_diff_eq(once.progress(), 0.3, 1e-5));
+        assert_eq!(once.times_completed(), 0);
+        once.set_elapsed(Duration::from_secs(5));
+        assert_eq!(once.elapsed(), Duration::from_secs(1));
+        assert_eq!(once.times_completed(), 1);
+
+        // Looping tweens count every iteration exactly
+        let mut looping = tween(TweeningType::Loop);
+        looping.set_elapsed(Duration::from_millis(1_000_250));
+        assert_eq!(looping.elapsed(), Duration::from_millis(1_000_250));
+        assert_eq!(looping.times_completed(), 1000);
+        assert!(abs_diff_eq(looping.progress(), 0.25, 1e-5));
+
+        // Ping-pong tweens alternate direction with each iteration, relative to the initial one
+        let mut ping_pong = tween(TweeningType::PingPong);
+        ping_pong.set_elapsed(Duration::from_millis(3_250));
+        assert_eq!(ping_pong.times_completed(), 3);
+        assert_eq!(ping_pong.direction(), TweeningDirection::Backward);
+        assert!(abs_diff_eq(ping_pong.progress(), 0.25, 1e-5));
+        ping_pong.set_elapsed(Duration::from_millis(1_250));
+        assert_eq!(ping_pong.times_completed(), 1);
+        assert_eq!(ping_pong.direction(), TweeningDirection::Backward);
+        ping_pong.set_elapsed(Duration::from_millis(250));
+        assert_eq!(ping_pong.times_completed(), 0);
+        assert_eq!(ping_pong.direction(), TweeningDirection::Forward);
+
+        let mut ping_pong =
+            tween(TweeningType::PingPong).with_direction(TweeningDirection::Backward);
+        ping_pong.set_elapsed(Duration::from_millis(2_250));
+        assert_eq!(ping_pong.direction(), TweeningDirection::Backward);
+        ping_pong.set_elapsed(Duration::from_millis(1_250));
+        assert_eq!(ping_pong.direction(), TweeningDirection::Forward);
+
+        // Seeking matches ticking from the start
+        let mut ticked = tween(TweeningType::PingPongTimes(3));
+        let mut seeked = tween(TweeningType::PingPongTimes(3));
+        for i in 1..=6 {
+            ticked.tick(
+                Duration::from_millis(500),
+                &mut Transform::default(),
+                &mut (),
+            );
+            seeked.set_elapsed(Duration::from_millis(500) * i);
+            assert_eq!(seeked.times_completed(), ticked.times_completed());
+            assert_eq!(seeked.direction(), ticked.direction());
+            assert_eq!(seeked.elapsed(), ticked.elapsed());
+            assert!(abs_diff_eq(seeked.progress(), ticked.progress(), 1e-5));
+        }
+        seeked.set_elapsed(Duration::from_secs(10));
+        assert_eq!(seeked.elapsed(), Duration::from_secs(3));
+        assert_eq!(seeked.times_completed(), 3);
+    }
+
+    /// Test seeking sequences, tracks and delays by absolute time.
+    #[test]
+    fn seq_tracks_elapsed() {
+        let tween = |duration| {
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                duration,
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+
+        let mut seq = Delay::new(Duration::from_secs(1))
+            .then(tween(Duration::from_secs(1)))
+            .then(tween(Duration::from_secs(2)));
+        seq.set_elapsed(Duration::from_millis(2_500));
+        assert_eq!(seq.elapsed(), Duration::from_millis(2_500));
+        assert_eq!(seq.index(), Some(2));
+        assert_eq!(seq.current().unwrap().elapsed(), Duration::from_millis(500));
+        assert_eq!(seq.times_completed(), 0);
+        seq.set_elapsed(Duration::from_millis(500));
+        assert_eq!(seq.index(), Some(0));
+        assert_eq!(seq.current().unwrap().elapsed(), Duration::from_millis(500));
+        seq.set_elapsed(Duration::from_secs(10));
+        assert_eq!(seq.elapsed(), Duration::from_secs(4));
+        assert_eq!(seq.times_completed(), 1);
+
+        let mut tracks =
+            Tracks::new([tween(Duration::from_secs(1)), tween(Duration::from_secs(2))]);
+        tracks.set_elapsed(Duration::from_millis(1_500));
+        assert_eq!(tracks.elapsed(), Duration::from_millis(1_500));
+        assert!(abs_diff_eq(tracks.progress(), 0.75, 1e-5));
+        assert_eq!(tracks.times_completed(), 0);
+        tracks.set_elapsed(Duration::from_secs(3));
+        assert_eq!(tracks.elapsed(), Duration::from_secs(2));
+        assert_eq!(tracks.times_completed(), 1);
+
+        let mut delay = Delay::new(Duration::from_secs(1));
+        Tweenable::<Transform>::set_elapsed(&mut delay, Duration::from_millis(400));
+        assert_eq!(
+            Tweenable::<Transform>::elapsed(&delay),
+            Duration::from_millis(400)
+        );
+        assert_eq!(Tweenable::<Transform>::times_completed(&delay), 0);
+        Tweenable::<Transform>::set_elapsed(&mut delay, Duration::from_secs(2));
+        assert_eq!(
+            Tweenable::<Transform>::elapsed(&delay),
+            Duration::from_secs(1)
+        );
+        assert_eq!(Tweenable::<Transform>::times_completed(&delay), 1);
+    }
+
+    /// Test zero-length tweens jump to their end, without any NaN.
+    #[test]
+    fn tween_zero_duration() {
+        let tween = |tweening_type| {
+            Tween::new(
+                EaseMethod::Linear,
+                tweening_type,
+                Duration::ZERO,
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+            .with_completed_event(true, 0)
+        };
+
+        let mut once = tween(TweeningType::Once);
+        assert_eq!(once.progress(), 1.);
+        let mut sampled = Transform::default();
+        once.sample_at(Duration::ZERO, &mut sampled);
+        assert!(sampled.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+
+        // The first tick completes the tween, even with a zero delta
+        let mut transform = Transform::default();
+        let mut completions = vec![];
+        let state = once.tick(Duration::ZERO, &mut transform, &mut completions);
+        assert_eq!(state, TweenState::Completed);
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert_eq!(completions, vec![0]);
+        assert_eq!(once.times_completed(), 1);
+        once.tick(Duration::from_secs(1), &mut transform, &mut completions);
+        assert_eq!(completions, vec![0]);
+        assert!(once.is_idle());
+
+        // Playing backward jumps to the end too
+        once.set_speed(-1.);
+        let state = once.tick(Duration::ZERO, &mut transform, &mut ());
+        assert_eq!(state, TweenState::Completed);
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+
+        // Zero-length loops complete a single iteration per tick
+        let mut looping = tween(TweeningType::PingPongTimes(2));
+        let mut completions = vec![];
+        let state = looping.tick(Duration::from_secs(1), &mut transform, &mut completions);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(looping.direction(), TweeningDirection::Backward);
+        let state = looping.tick(Duration::from_secs(1), &mut transform, &mut completions);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(completions, vec![0, 0]);
+        assert!(!transform.translation.is_nan());
+
+        // Only the fallible constructor rejects them
+        let lens = TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::ONE,
+        };
+        assert!(
+            Tween::try_new(EaseMethod::Linear, TweeningType::Once, Duration::ZERO, lens).is_ok()
+        );
+        assert_eq!(
+            Tween::try_new(EaseMethod::Linear, TweeningType::Loop, Duration::ZERO, lens).err(),
+            Some(TweeningError::ZeroDurationLoop)
+        );
+        assert!(Tween::try_new(
+            EaseMethod::Linear,
+            TweeningType::Loop,
+            Duration::from_secs(1),
+            lens
+        )
+        .is_ok());
+    }
+
+    /// Test empty and zero-length sequences, tracks and delays.
+    #[test]
+    fn seq_tracks_empty() {
+        let empty = Vec::<Tween<Transform>>::new;
+        assert_eq!(
+            Sequence::try_new(empty()).err().map(|err| err.to_string()),
+            Some("sequence without any tweenable".to_string())
+        );
+        assert_eq!(
+            Tracks::try_new(empty()).err(),
+            Some(TweeningError::EmptyTracks)
+        );
+
+        // The infallible constructors build empty tweenables completing on their first tick
+        let mut seq = Sequence::new(empty());
+        assert_eq!(seq.duration(), Duration::ZERO);
+        assert_eq!(seq.progress(), 1.);
+        assert_eq!(seq.index(), None);
+        assert!(seq.current().is_none());
+        let state = seq.tick(Duration::ZERO, &mut Transform::default(), &mut ());
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(seq.index(), None);
+        assert!(seq.current().is_none());
+        let mut tracks = Tracks::new(empty());
+        assert_eq!(tracks.duration(), Duration::ZERO);
+        assert_eq!(tracks.progress(), 1.);
+        let state = tracks.tick(Duration::ZERO, &mut Transform::default(), &mut ());
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(tracks.times_completed(), 1);
+
+        // A zero-length delay at the start of a sequence is skipped
+        let mut seq = Delay::new(Duration::ZERO).then(Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        ));
+        assert_eq!(
+            Tweenable::<Transform>::progress(&Delay::new(Duration::ZERO)),
+            1.
+        );
+        let mut transform = Transform::default();
+        seq.tick(Duration::from_millis(500), &mut transform, &mut ());
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+    }
+
+    /// Test sampling tweens at arbitrary times, without mutating them.
+    #[test]
+    fn tween_sample_at() {
+        let tween = |tweening_type| {
+            Tween::new(
+                EaseMethod::Linear,
+                tweening_type,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+            .with_completed_event(true, 0)
+        };
+
+        for tweening_type in [
+            TweeningType::Once,
+            TweeningType::Loop,
+            TweeningType::PingPong,
+            TweeningType::PingPongTimes(3),
+        ] {
+            let mut tween = tween(tweening_type);
+            tween.tick(
+                Duration::from_millis(1_700),
+                &mut Transform::default(),
+                &mut (),
+            );
+            let (elapsed, direction) = (tween.elapsed(), tween.direction());
+            let (times_completed, progress) = (tween.times_completed(), tween.progress());
+
+            for millis in [0, 250, 1_000, 1_500, 2_250, 10_000] {
+                let mut sampled = Transform::default();
+                tween.sample_at(Duration::from_millis(millis), &mut sampled);
+
+                // Sampling matches seeking then applying a copy of the tween
+                let mut seeked = Tween::new(
+                    EaseMethod::Linear,
+                    tweening_type,
+                    Duration::from_secs(1),
+                    TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::ONE,
+                    },
+                );
+                seeked.set_elapsed(Duration::from_millis(millis));
+                let mut expected = Transform::default();
+                seeked.apply(&mut expected);
+                assert!(sampled.translation.abs_diff_eq(expected.translation, 1e-5));
+            }
+
+            // The tween state is left untouched
+            assert_eq!(tween.elapsed(), elapsed);
+            assert_eq!(tween.direction(), direction);
+            assert_eq!(tween.times_completed(), times_completed);
+            assert!(abs_diff_eq(tween.progress(), progress, 1e-5));
+        }
+
+        // Ping-pong tweens sample relative to their initial direction
+        let mut ping_pong = tween(TweeningType::PingPong);
+        ping_pong.tick(
+            Duration::from_millis(1_200),
+            &mut Transform::default(),
+            &mut (),
+        );
+        assert_eq!(ping_pong.direction(), TweeningDirection::Backward);
+        let mut sampled = Transform::default();
+        ping_pong.sample_at(Duration::from_millis(250), &mut sampled);
+        assert!(sampled.translation.abs_diff_eq(Vec3::splat(0.25), 1e-5));
+        ping_pong.sample_at(Duration::from_millis(1_250), &mut sampled);
+        assert!(sampled.translation.abs_diff_eq(Vec3::splat(0.75), 1e-5));
+    }
+
+    /// Test sampling a tween with a stateful lens leaves the lens state untouched.
+    #[test]
+    fn tween_sample_at_stateful_lens() {
+        let mut calls = 0;
+        let mut tween = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            ClosureLens::new(move |transform: &mut Transform, ratio| {
+                calls += 1;
+                transform.translation = Vec3::new(ratio, calls as f32, 0.);
+            }),
+        );
+
+        let mut target = Transform::default();
+        tween.apply(&mut target);
+        assert_eq!(target.translation, Vec3::new(0., 1., 0.));
+
+        let mut sampled = Transform::default();
+        tween.sample_at(Duration::from_millis(500), &mut sampled);
+        assert_eq!(sampled.translation, Vec3::new(0.5, 2., 0.));
+        tween.sample_at(Duration::from_millis(500), &mut sampled);
+        assert_eq!(sampled.translation, Vec3::new(0.5, 2., 0.));
+
+        // Sampling cloned the lens, so the tween's own lens only saw the first write
+        tween.apply(&mut target);
+        assert_eq!(target.translation, Vec3::new(0., 2., 0.));
+    }
+
+    /// Test cloning tweens, including their state and completion callback.
+    #[test]
+    fn tween_clone() {
+        let mut tween = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_event(true, 42);
+        let invoke_count = Arc::new(Mutex::new(0));
+        let count = Arc::clone(&invoke_count);
+        tween.set_completed(move |_, _| *count.lock().unwrap() += 1);
+        tween.tick(
+            Duration::from_millis(400),
+            &mut Transform::default(),
+            &mut (),
+        );
+        let elapsed = tween.elapsed();
+
+        // The clone starts from the state of the original tween
+        let mut clone = tween.clone();
+        assert_eq!(clone.elapsed(), elapsed);
+        assert_eq!(clone.direction(), tween.direction());
+
+        // The clone is independent, but shares the completion callback
+        let mut transform = Transform::default();
+        let mut completions = vec![];
+        let state = clone.tick(Duration::from_secs(1), &mut transform, &mut completions);
+        assert_eq!(state, TweenState::Completed);
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert_eq!(completions, vec![42]);
+        assert_eq!(*invoke_count.lock().unwrap(), 1);
+        assert_eq!(tween.elapsed(), elapsed);
+        assert_eq!(tween.times_completed(), 0);
+
+        // The clone writes its state to a new target even if it didn't change
+        let mut clone = clone.clone();
+        let mut transform = Transform::default();
+        clone.tick(Duration::ZERO, &mut transform, &mut ());
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+    }
+
+    /// Test cloning sequences and tracks of boxed tweenables.
+    #[test]
+    fn seq_tracks_clone() {
+        let tween = |end| {
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end,
+                },
+            )
+        };
+
+        let mut seq = Delay::new(Duration::from_secs(1))
+            .then(tween(Vec3::ONE))
+            .then(tween(Vec3::splat(2.)));
+        seq.tick(
+            Duration::from_millis(1_500),
+            &mut Transform::default(),
+            &mut (),
+        );
+        let mut clone = seq.clone();
+        assert_eq!(clone.index(), Some(1));
+        assert_eq!(clone.elapsed(), Duration::from_millis(1_500));
+        let mut transform = Transform::default();
+        clone.tick(Duration::from_secs(1), &mut transform, &mut ());
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert_eq!(clone.index(), Some(2));
+        assert_eq!(seq.index(), Some(1));
+
+        let mut tracks = Tracks::new([
+            tween(Vec3::ONE),
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs(2),
+                TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(3.),
+                },
+            ),
+        ]);
+        tracks.tick(
+            Duration::from_millis(500),
+            &mut Transform::default(),
+            &mut (),
+        );
+        let mut clone = tracks.clone();
+        let mut transform = Transform::default();
+        let state = clone.tick(Duration::from_millis(1_500), &mut transform, &mut ());
+        assert_eq!(state, TweenState::Completed);
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(3.), 1e-5));
+        assert_eq!(tracks.elapsed(), Duration::from_millis(500));
+
+        // Type-erased tweenables are cloned through their concrete type
+        let boxed: BoxedTweenable<Transform> = Box::new(tracks);
+        let clone = boxed.clone();
+        assert_eq!(clone.elapsed(), Duration::from_millis(500));
+        assert_eq!(clone.duration(), Duration::from_secs(2));
+    }
+
+    /// Test sampling sequences and tracks at arbitrary times, without mutating them.
+    #[test]
+    fn seq_tracks_sample_at() {
+        let tween = |duration, end| {
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                duration,
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end,
+                },
+            )
+        };
+
+        let seq = Delay::new(Duration::from_secs(1))
+            .then(tween(Duration::from_secs(1), Vec3::ONE))
+            .then(tween(Duration::from_secs(2), Vec3::splat(2.)));
+
+        // Not started yet, nothing written
+        let mut sampled = Transform::from_translation(Vec3::splat(-1.));
+        seq.sample_at(Duration::from_millis(500), &mut sampled);
+        assert!(sampled.translation.abs_diff_eq(Vec3::splat(-1.), 1e-5));
+
+        seq.sample_at(Duration::from_millis(1_500), &mut sampled);
+        assert!(sampled.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+        seq.sample_at(Duration::from_millis(3_000), &mut sampled);
+        assert!(sampled.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        seq.sample_at(Duration::from_secs(10), &mut sampled);
+        assert!(sampled.translation.abs_diff_eq(Vec3::splat(2.), 1e-5));
+        assert_eq!(seq.elapsed(), Duration::ZERO);
+        assert_eq!(seq.index(), Some(0));
+
+        let tracks = Tracks::new([
+            tween(Duration::from_secs(1), Vec3::ONE),
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs(2),
+                TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(3.),
+                },
+            ),
+        ]);
+        let mut sampled = Transform::default();
+        tracks.sample_at(Duration::from_millis(1_500), &mut sampled);
+        assert!(sampled.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert!(sampled.scale.abs_diff_eq(Vec3::splat(2.5), 1e-5));
+        assert_eq!(tracks.elapsed(), Duration::ZERO);
+        assert_eq!(tracks.times_completed(), 0);
+    }
+
     /// Test ticking a sequence of tweens.
     #[test]
     fn seq_tick() {
@@ -1210,20 +2594,8 @@ mod tests {
         let mut seq = tween1.then(tween2);
         let mut transform = Transform::default();
 
-        // Dummy world and event writer
-        let mut world = World::new();
-        world.insert_resource(Events::<TweenCompleted>::default());
-        let mut system_state: SystemState<EventWriter<TweenCompleted>> =
-            SystemState::new(&mut world);
-        let mut event_writer = system_state.get_mut(&mut world);
-
         for i in 1..=16 {
-            let state = seq.tick(
-                Duration::from_secs_f32(0.2),
-                &mut transform,
-                Entity::from_raw(0),
-                &mut event_writer,
-            );
+            let state = seq.tick(Duration::from_secs_f32(0.2), &mut transform, &mut ());
             if i < 5 {
                 assert_eq!(state, TweenState::Active);
                 let r = i as f32 * 0.2;
@@ -1268,20 +2640,8 @@ mod tests {
         let mut seq = tween1.then(tween2);
         let mut transform = Transform::default();
 
-        // Dummy world and event writer
-        let mut world = World::new();
-        world.insert_resource(Events::<TweenCompleted>::default());
-        let mut system_state: SystemState<EventWriter<TweenCompleted>> =
-            SystemState::new(&mut world);
-        let mut event_writer = system_state.get_mut(&mut world);
-
         for i in 1..=16 {
-            let state = seq.tick(
-                Duration::from_secs_f32(0.3),
-                &mut transform,
-                Entity::from_raw(0),
-                &mut event_writer,
-            );
+            let state = seq.tick(Duration::from_secs_f32(0.3), &mut transform, &mut ());
             if i < 4 {
                 assert_eq!(state, TweenState::Active);
                 let r = i as f32 * 0.3;
@@ -1326,20 +2686,8 @@ mod tests {
         let mut seq = tween1.then(tween2);
         let mut transform = Transform::default();
 
-        // Dummy world and event writer
-        let mut world = World::new();
-        world.insert_resource(Events::<TweenCompleted>::default());
-        let mut system_state: SystemState<EventWriter<TweenCompleted>> =
-            SystemState::new(&mut world);
-        let mut event_writer = system_state.get_mut(&mut world);
-
         for i in 1..=2 {
-            let state = seq.tick(
-                Duration::from_secs_f32(1.3),
-                &mut transform,
-                Entity::from_raw(0),
-                &mut event_writer,
-            );
+            let state = seq.tick(Duration::from_secs_f32(1.3), &mut transform, &mut ());
             if i < 2 {
                 assert_eq!(state, TweenState::Active);
                 let alpha_deg = 27f32;
@@ -1375,10 +2723,13 @@ mod tests {
 
         let mut progress = 0.;
         for i in 1..5 {
-            assert_eq!(seq.index(), i - 1);
+            assert_eq!(seq.index(), Some(i - 1));
             assert!((seq.progress() - progress).abs() < 1e-5);
             let secs = 0.2 * i as f32;
-            assert_eq!(seq.current().duration(), Duration::from_secs_f32(secs));
+            assert_eq!(
+                seq.current().unwrap().duration(),
+                Duration::from_secs_f32(secs)
+            );
             progress += 0.25;
             seq.set_progress(progress);
             assert_eq!(seq.times_completed(), if i == 4 { 1 } else { 0 });
@@ -1428,8 +2779,12 @@ mod tests {
                     .find_position(|p| progress < p)
                     .map(|p| p.0)
                     .unwrap_or(progression.len() - 1)
+                    .into()
+            );
+            assert_eq!(
+                seq.current().unwrap().duration(),
+                durations[seq.index().unwrap()]
             );
-            assert_eq!(seq.current().duration(), durations[seq.index()]);
             assert_eq!(seq.times_completed(), if *progress == 1. { 1 } else { 0 });
         }
     }
@@ -1461,20 +2816,8 @@ mod tests {
 
         let mut transform = Transform::default();
 
-        // Dummy world and event writer
-        let mut world = World::new();
-        world.insert_resource(Events::<TweenCompleted>::default());
-        let mut system_state: SystemState<EventWriter<TweenCompleted>> =
-            SystemState::new(&mut world);
-        let mut event_writer = system_state.get_mut(&mut world);
-
         for i in 1..=6 {
-            let state = tracks.tick(
-                Duration::from_secs_f32(0.2),
-                &mut transform,
-                Entity::from_raw(0),
-                &mut event_writer,
-            );
+            let state = tracks.tick(Duration::from_secs_f32(0.2), &mut transform, &mut ());
             if i < 5 {
                 assert_eq!(state, TweenState::Active);
                 assert_eq!(tracks.times_completed(), 0);
@@ -1503,36 +2846,21 @@ mod tests {
         tracks.set_progress(0.9);
         assert!((tracks.progress() - 0.9).abs() < 1e-5);
         // tick to udpate state (set_progress() does not update state)
-        let state = tracks.tick(
-            Duration::from_secs_f32(0.),
-            &mut transform,
-            Entity::from_raw(0),
-            &mut event_writer,
-        );
+        let state = tracks.tick(Duration::from_secs_f32(0.), &mut transform, &mut ());
         assert_eq!(state, TweenState::Active);
         assert_eq!(tracks.times_completed(), 0);
 
         tracks.set_progress(3.2);
         assert!((tracks.progress() - 1.).abs() < 1e-5);
         // tick to udpate state (set_progress() does not update state)
-        let state = tracks.tick(
-            Duration::from_secs_f32(0.),
-            &mut transform,
-            Entity::from_raw(0),
-            &mut event_writer,
-        );
+        let state = tracks.tick(Duration::from_secs_f32(0.), &mut transform, &mut ());
         assert_eq!(state, TweenState::Completed);
         assert_eq!(tracks.times_completed(), 1); // no looping
 
         tracks.set_progress(-0.5);
         assert!(tracks.progress().abs() < 1e-5);
         // tick to udpate state (set_progress() does not update state)
-        let state = tracks.tick(
-            Duration::from_secs_f32(0.),
-            &mut transform,
-            Entity::from_raw(0),
-            &mut event_writer,
-        );
+        let state = tracks.tick(Duration::from_secs_f32(0.), &mut transform, &mut ());
         assert_eq!(state, TweenState::Active);
         assert_eq!(tracks.times_completed(), 0); // no looping
     }
@@ -1551,20 +2879,8 @@ mod tests {
 
         let mut transform = Transform::default();
 
-        // Dummy world and event writer
-        let mut world = World::new();
-        world.insert_resource(Events::<TweenCompleted>::default());
-        let mut system_state: SystemState<EventWriter<TweenCompleted>> =
-            SystemState::new(&mut world);
-        let mut event_writer = system_state.get_mut(&mut world);
-
         for i in 1..=6 {
-            let state = delay.tick(
-                Duration::from_secs_f32(0.2),
-                &mut transform,
-                Entity::from_raw(0),
-                &mut event_writer,
-            );
+            let state = delay.tick(Duration::from_secs_f32(0.2), &mut transform, &mut ());
             {
                 let tweenable: &dyn Tweenable<Transform> = &delay;
                 if i < 5 {
@@ -1578,4 +2894,211 @@ mod tests {
             }
         }
     }
+
+    /// Mirror the state of a sequence ticked on a target onto other targets.
+    #[test]
+    fn seq_apply() {
+        let tween1 = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs_f32(1.),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let tween2 = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs_f32(1.),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            },
+        );
+        let tween3 = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs_f32(1.),
+            TransformRotationLens {
+                start: Quat::IDENTITY,
+                end: Quat::from_rotation_x(90_f32.to_radians()),
+            },
+        );
+        let mut seq = Delay::new(Duration::from_secs_f32(0.5))
+            .then(tween1)
+            .then(Tracks::new([tween2]))
+            .then(tween3);
+
+        // Not started yet, nothing applied
+        let mut mirror = Transform::from_translation(Vec3::splat(-1.));
+        seq.apply(&mut mirror);
+        assert!(mirror.translation.abs_diff_eq(Vec3::splat(-1.), 1e-5));
+
+        // Crossing the boundary of the first two tweens in a single tick
+        let mut transform = Transform::default();
+        seq.tick(Duration::from_secs_f32(2.), &mut transform, &mut ());
+        let mut mirror = Transform::default();
+        seq.apply(&mut mirror);
+        assert!(mirror.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert!(mirror.scale.abs_diff_eq(Vec3::splat(1.5), 1e-5));
+        assert!(mirror.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert_eq!(mirror, transform);
+
+        // Completed sequence applies the final state
+        seq.tick(Duration::from_secs_f32(2.), &mut transform, &mut ());
+        let mut mirror = Transform::default();
+        seq.apply(&mut mirror);
+        assert!(mirror.scale.abs_diff_eq(Vec3::splat(2.), 1e-5));
+        assert!(mirror
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_x(90_f32.to_radians()), 1e-5));
+        assert_eq!(mirror, transform);
+    }
+
+    /// Test the playback rate of a single tween, including reverse playback.
+    #[test]
+    fn tween_speed() {
+        let mut tween = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::Once,
+            Duration::from_secs_f32(1.),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_speed(2.);
+        assert!(abs_diff_eq(tween.speed(), 2., 1e-5));
+        assert_eq!(tween.duration(), Duration::from_secs_f32(1.));
+
+        let mut transform = Transform::default();
+        let tick = |tween: &mut Tween<Transform>, transform: &mut Transform| {
+            tween.tick(Duration::from_secs_f32(0.2), transform, &mut ())
+        };
+
+        // Twice as fast
+        assert_eq!(tick(&mut tween, &mut transform), TweenState::Active);
+        assert!(abs_diff_eq(tween.progress(), 0.4, 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.4), 1e-5));
+
+        // Frozen
+        tween.set_speed(0.);
+        assert_eq!(tick(&mut tween, &mut transform), TweenState::Active);
+        assert!(abs_diff_eq(tween.progress(), 0.4, 1e-5));
+
+        // Backward, down to the start
+        tween.set_speed(-1.);
+        assert_eq!(tick(&mut tween, &mut transform), TweenState::Active);
+        assert!(abs_diff_eq(tween.progress(), 0.2, 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.2), 1e-5));
+        assert_eq!(tick(&mut tween, &mut transform), TweenState::Completed);
+        assert!(tween.progress().abs() < 1e-5);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert_eq!(tween.times_completed(), 0);
+
+        // Backward over the start of a ping-pong iteration reverses the direction
+        let mut tween = Tween::new(
+            EaseMethod::Linear,
+            TweeningType::PingPong,
+            Duration::from_secs_f32(1.),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_speed(-1.);
+        assert_eq!(tick(&mut tween, &mut transform), TweenState::Active);
+        assert_eq!(tween.direction(), TweeningDirection::Backward);
+        assert!(abs_diff_eq(tween.progress(), 0.8, 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.2), 1e-5));
+    }
+
+    /// Test the playback rate of sequences and tracks, including reverse playback.
+    #[test]
+    fn seq_tracks_speed() {
+        let tween = |lens: TransformPositionLens| {
+            Tween::new(
+                EaseMethod::Linear,
+                TweeningType::Once,
+                Duration::from_secs_f32(1.),
+                lens,
+            )
+        };
+        let mut seq = Delay::new(Duration::from_secs_f32(1.))
+            .then(tween(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            }))
+            .then(tween(TransformPositionLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            }));
+        seq.set_speed(2.);
+        assert!(abs_diff_eq(seq.speed(), 2., 1e-5));
+        assert_eq!(seq.duration(), Duration::from_secs_f32(3.));
+
+        let mut transform = Transform::default();
+        let tick =
+            |tweenable: &mut dyn Tweenable<Transform>, transform: &mut Transform, secs: f32| {
+                tweenable.tick(Duration::from_secs_f32(secs), transform, &mut ())
+            };
+
+        // Twice as fast, crossing the end of the delay and of the first tween
+        assert_eq!(tick(&mut seq, &mut transform, 1.2), TweenState::Active);
+        assert!(abs_diff_eq(seq.progress(), 0.8, 1e-5));
+        assert_eq!(seq.index(), Some(2));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(1.4), 1e-5));
+
+        // Backward into the first tween, which is restored alone
+        seq.set_speed(-1.);
+        assert_eq!(tick(&mut seq, &mut transform, 0.8), TweenState::Active);
+        assert!(abs_diff_eq(seq.progress(), 0.5333333, 1e-5));
+        assert_eq!(seq.index(), Some(1));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.6), 1e-5));
+
+        // Backward into the delay, then to the start
+        assert_eq!(tick(&mut seq, &mut transform, 0.7), TweenState::Active);
+        assert_eq!(seq.index(), Some(0));
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert_eq!(tick(&mut seq, &mut transform, 1.), TweenState::Completed);
+        assert!(seq.progress().abs() < 1e-5);
+
+        // Tracks play all their tracks at the same rate
+        let mut tracks = Tracks::new([
+            tween(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            }),
+            tween(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            }),
+        ]);
+        tracks.set_speed(0.5);
+        assert_eq!(tick(&mut tracks, &mut transform, 1.), TweenState::Active);
+        assert!(abs_diff_eq(tracks.progress(), 0.5, 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+        tracks.set_speed(0.);
+        assert_eq!(tick(&mut tracks, &mut transform, 1.), TweenState::Active);
+        assert!(abs_diff_eq(tracks.progress(), 0.5, 1e-5));
+        tracks.set_speed(-1.);
+        assert_eq!(tick(&mut tracks, &mut transform, 1.), TweenState::Completed);
+        assert!(tracks.progress().abs() < 1e-5);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+
+        // Delays wait at the same rate
+        let mut delay = Delay::new(Duration::from_secs_f32(1.));
+        Tweenable::<Transform>::set_speed(&mut delay, 4.);
+        assert_eq!(tick(&mut delay, &mut transform, 0.2), TweenState::Active);
+        assert_eq!(tick(&mut delay, &mut transform, 0.2), TweenState::Completed);
+        Tweenable::<Transform>::set_speed(&mut delay, -1.);
+        assert_eq!(tick(&mut delay, &mut transform, 0.5), TweenState::Active);
+        assert!(abs_diff_eq(
+            Tweenable::<Transform>::progress(&delay),
+            0.5,
+            1e-5
+        ));
+        assert_eq!(tick(&mut delay, &mut transform, 0.5), TweenState::Completed);
+    }
 }